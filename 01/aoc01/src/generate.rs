@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` lines of two location ids, with some ids shared between the lists so part 2
+/// has something to count
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let ids = (0..size.max(1))
+        .map(|_| rng.random_range(10_000..100_000u32))
+        .collect::<Vec<u32>>();
+
+    let mut out = String::new();
+    for &left in &ids {
+        let right = if rng.random_bool(0.3) {
+            ids[rng.random_range(0..ids.len())]
+        } else {
+            rng.random_range(10_000..100_000)
+        };
+        out.push_str(&format!("{}   {}\n", left, right));
+    }
+    out
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+extern crate anyhow;
+use anyhow::anyhow;
+
+use aoc_common::{parse_num, ParseError, Solution};
+
+pub mod generate;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = (Vec<u32>, Vec<u32>);
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lists = (Vec::new(), Vec::new());
+        for (i, line) in input.lines().enumerate() {
+            let mut iter = line.split_whitespace();
+            let (Some(n1), Some(n2)) = (iter.next(), iter.next()) else {
+                return Err(ParseError::at(i, line, line, "two location ids"));
+            };
+            lists.0.push(parse_num(i, line, n1)?);
+            lists.1.push(parse_num(i, line, n2)?);
+        }
+
+        Ok(lists)
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        let (mut list1, mut list2) = data.clone();
+        list1.sort_unstable();
+        list2.sort_unstable();
+
+        list1
+            .into_iter()
+            .zip(list2)
+            .try_fold(0u32, |total, (n1, n2)| total.checked_add(n1.abs_diff(n2)))
+            .ok_or_else(|| anyhow!("total distance doesn't fit in a u32"))
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        let (list1, list2) = data;
+        let counts = list2.iter().fold(HashMap::<u32, u32>::new(), |mut map, n| {
+            map.entry(*n).and_modify(|v| *v += 1).or_insert(1);
+            map
+        });
+
+        list1
+            .iter()
+            .try_fold(0u32, |total, n| {
+                total.checked_add(counts.get(n).copied().unwrap_or(0).checked_mul(*n)?)
+            })
+            .ok_or_else(|| anyhow!("similarity score doesn't fit in a u32"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day01.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&data).unwrap(), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part2(&data).unwrap(), 31);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let data = Day01::parse("0 4294967295\n1 4294967295\n").unwrap();
+        assert!(Day01::part1(&data).is_err());
+        let data = Day01::parse("4294967295 4294967295\n0 4294967295\n").unwrap();
+        assert!(Day01::part2(&data).is_err());
+    }
+}
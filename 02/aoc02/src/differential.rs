@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+
+use super::Day02;
+
+/// safe straight from the puzzle's wording: all increasing or all decreasing, by 1 to 3
+fn is_safe(levels: &[u32]) -> bool {
+    let increasing = levels
+        .windows(2)
+        .all(|pair| (1..=3).contains(&(pair[1] as i64 - pair[0] as i64)));
+    let decreasing = levels
+        .windows(2)
+        .all(|pair| (1..=3).contains(&(pair[0] as i64 - pair[1] as i64)));
+    increasing || decreasing
+}
+
+/// safe once any one level, or none, is taken out
+fn is_dampened_safe(levels: &[u32]) -> bool {
+    is_safe(levels)
+        || (0..levels.len()).any(|skip| {
+            let mut shorter = levels.to_vec();
+            shorter.remove(skip);
+            is_safe(&shorter)
+        })
+}
+
+fn to_input(reports: &[Vec<u32>]) -> String {
+    reports
+        .iter()
+        .map(|levels| {
+            let levels = levels.iter().map(u32::to_string).collect::<Vec<String>>();
+            format!("{}\n", levels.join(" "))
+        })
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force(reports in prop::collection::vec(prop::collection::vec(1u32..15, 1..8), 0..20)) {
+        let data = Day02::parse(&to_input(&reports)).unwrap();
+
+        let safe = reports.iter().filter(|levels| is_safe(levels)).count() as u32;
+        prop_assert_eq!(Day02::part1(&data).unwrap(), safe);
+        let dampened = reports.iter().filter(|levels| is_dampened_safe(levels)).count() as u32;
+        prop_assert_eq!(Day02::part2(&data).unwrap(), dampened);
+    }
+}
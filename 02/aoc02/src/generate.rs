@@ -0,0 +1,35 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` reports of 5 to 8 levels, most of them steadily rising or falling with one bad
+/// level mixed in now and then
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let len = rng.random_range(5..=8);
+        let rising = rng.random_bool(0.5);
+        let mut level = rng.random_range(20..80i32);
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+            let step = rng.random_range(1..=3);
+            level += if rising { step } else { -step };
+        }
+
+        // knock a level out of line, which may or may not be fixable by removing it
+        if rng.random_bool(0.5) {
+            let index = rng.random_range(0..len);
+            levels[index] += rng.random_range(-4..=4);
+        }
+
+        let levels = levels
+            .iter()
+            .map(|&l| l.max(1).to_string())
+            .collect::<Vec<String>>();
+        out.push_str(&levels.join(" "));
+        out.push('\n');
+    }
+    out
+}
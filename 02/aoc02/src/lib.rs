@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+extern crate anyhow;
+
+use aoc_common::{parse_num, ParseError, Solution};
+use tracing::debug;
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Vec<u32>>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if line.trim().is_empty() {
+                    return Err(ParseError::at(i, line, line, "a report of levels"));
+                }
+                line.split_whitespace()
+                    .map(|n| parse_num(i, line, n))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        let mut safe_count = 0;
+        'lvl_loop: for lvl in data {
+            // a single level has nothing to go wrong with
+            if lvl.len() < 2 {
+                safe_count += 1;
+                continue;
+            }
+            let transition = match lvl[0].cmp(&lvl[1]) {
+                Ordering::Less => "less",
+                Ordering::Greater => "Greater",
+                Ordering::Equal => continue,
+            };
+
+            for pair in lvl[0..lvl.len()].windows(2) {
+                match pair[0].cmp(&pair[1]) {
+                    Ordering::Less => {
+                        if transition == "Greater" {
+                            continue 'lvl_loop;
+                        }
+                    }
+                    Ordering::Greater => {
+                        if transition == "less" {
+                            continue 'lvl_loop;
+                        }
+                    }
+                    Ordering::Equal => continue 'lvl_loop,
+                }
+
+                if pair[0].abs_diff(pair[1]) > 3 {
+                    continue 'lvl_loop;
+                }
+            }
+
+            safe_count += 1;
+        }
+
+        Ok(safe_count)
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        let mut safe_count = 0;
+
+        for (report, line) in (1..).zip(data) {
+            match get_safety(line) {
+                // if it's already safe, we're good
+                Safety::Safe => safe_count += 1,
+
+                // if it's unsafe, try removing each item and see if it becomes safe
+                Safety::Unsafe => {
+                    for index in 0..line.len() {
+                        let mut shorter_line = line.clone();
+                        shorter_line.remove(index);
+                        if let Safety::Safe = get_safety(&shorter_line) {
+                            debug!(
+                                "report {} unsafe, removing index {} makes it safe",
+                                report, index
+                            );
+                            safe_count += 1;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(safe_count)
+    }
+}
+
+fn get_safety(line: &[u32]) -> Safety {
+    let mut increasing = Vec::new();
+    let mut decreasing = Vec::new();
+
+    for (i, pair) in line[..].windows(2).enumerate() {
+        // if there's a difference greater than 3, we violated a rule
+        if pair[0].abs_diff(pair[1]) > 3 {
+            return Safety::Unsafe;
+        }
+        // otherwise, keep track of who's increasing and who's decreasing
+        match pair[1].cmp(&pair[0]) {
+            Ordering::Greater => increasing.push(i),
+            Ordering::Less => decreasing.push(i),
+            // if it's equal, we violated a rule
+            Ordering::Equal => return Safety::Unsafe,
+        };
+    }
+
+    // if we have both increasing and decreasing, we violated a rule
+    if !increasing.is_empty() && !decreasing.is_empty() {
+        return Safety::Unsafe;
+    }
+
+    Safety::Safe
+}
+
+#[derive(Debug)]
+enum Safety {
+    Safe,
+    Unsafe,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day02.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&data).unwrap(), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part2(&data).unwrap(), 4);
+    }
+
+    #[test]
+    fn safety_of_example_reports() {
+        assert!(matches!(get_safety(&[7, 6, 4, 2, 1]), Safety::Safe));
+        assert!(matches!(get_safety(&[1, 3, 6, 7, 9]), Safety::Safe));
+        // a jump of more than 3
+        assert!(matches!(get_safety(&[1, 2, 7, 8, 9]), Safety::Unsafe));
+        // both increasing and decreasing
+        assert!(matches!(get_safety(&[1, 3, 2, 4, 5]), Safety::Unsafe));
+        // neither increasing nor decreasing
+        assert!(matches!(get_safety(&[8, 6, 4, 4, 1]), Safety::Unsafe));
+    }
+
+    #[test]
+    fn single_level_report_is_safe() {
+        let data = Day02::parse("5\n").unwrap();
+        assert_eq!(Day02::part1(&data).unwrap(), 1);
+        assert_eq!(Day02::part2(&data).unwrap(), 1);
+    }
+}
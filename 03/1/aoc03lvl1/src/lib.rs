@@ -1,71 +0,0 @@
-use std::fs;
-use std::io;
-use std::path::PathBuf;
-
-extern crate clap;
-use clap::Parser;
-
-extern crate anyhow;
-
-extern crate regex;
-use regex::Regex;
-
-#[derive(Parser, Debug)]
-pub struct Args {
-    /// path to the input file
-    #[arg(short, long)]
-    input: Option<PathBuf>,
-}
-
-enum InputConfig {
-    File(PathBuf),
-    Stdin,
-}
-pub struct Config {
-    input: InputConfig,
-}
-
-impl Config {
-    pub fn make() -> Self {
-        let args = Args::parse();
-        let input = if let Some(path) = args.input {
-            InputConfig::File(path)
-        } else {
-            InputConfig::Stdin
-        };
-
-        Config { input }
-    }
-}
-
-pub fn run(cfg: Config) -> anyhow::Result<u32> {
-    // figure out where to get our input from and read it into a string
-    let input_string = match cfg.input {
-        InputConfig::File(path) => fs::read_to_string(path)?,
-        InputConfig::Stdin => {
-            let mut buf = String::new();
-            io::stdin().read_line(&mut buf)?;
-            buf
-        }
-    };
-
-    let data = parse(input_string)?;
-    let result = process(data);
-    println!("{}", result);
-
-    Ok(result)
-}
-
-fn parse(input: String) -> anyhow::Result<Vec<(u32, u32)>> {
-    let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
-    let mut result = Vec::new();
-    for (_, [n, m]) in regex.captures_iter(&input).map(|c| c.extract()) {
-        result.push((n.parse::<u32>().unwrap(), m.parse::<u32>().unwrap()));
-    }
-
-    Ok(result)
-}
-
-fn process(data: Vec<(u32, u32)>) -> u32 {
-    data.into_iter().map(|(n, m)| n * m).sum()
-}
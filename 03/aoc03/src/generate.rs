@@ -0,0 +1,66 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// things that look a bit like instructions but aren't
+const NOISE: &[&str] = &[
+    "mul(4*",
+    "mul(6,9!",
+    "?(12,34)",
+    "mul ( 2 , 4 )",
+    "mul[3,7]",
+    "mul(1234,5)",
+    "do_not_",
+    "don't",
+    "do(",
+    "select()",
+    "from()",
+    "how()",
+    "when()",
+    "what()",
+    "'",
+    "%",
+    "&",
+    "^",
+    "!",
+    "@",
+    "#",
+    "$",
+    "*",
+    "+",
+    "-",
+    "/",
+    "<",
+    ">",
+    "[",
+    "]",
+    "{",
+    "}",
+    ",",
+    ")",
+    "(",
+];
+
+/// `size` chunks of corrupted memory, each a real `mul`, `do()`, `don't()` or noise
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut out = String::new();
+    for i in 0..size.max(1) {
+        match rng.random_range(0..10) {
+            0..=3 => out.push_str(&format!(
+                "mul({},{})",
+                rng.random_range(0..1000),
+                rng.random_range(0..1000)
+            )),
+            4 => out.push_str("do()"),
+            5 => out.push_str("don't()"),
+            _ => out.push_str(NOISE[rng.random_range(0..NOISE.len())]),
+        }
+        // the real input is a few long lines
+        if i % 60 == 59 {
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+    out
+}
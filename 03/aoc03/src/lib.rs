@@ -0,0 +1,100 @@
+extern crate anyhow;
+use anyhow::anyhow;
+
+extern crate regex;
+use regex::Regex;
+
+use aoc_common::{ParseError, Solution};
+
+pub mod generate;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Vec<Inst>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let regex = Regex::new(r"(mul\((\d{1,3}),(\d{1,3})\))|(do\(\))|(don\'t\(\))")
+            .expect("regex is valid");
+        let mut result = Vec::new();
+        for cap in regex.captures_iter(input) {
+            // the regex only lets through 1-3 digits, so these always fit
+            if let (Some(x), Some(y)) = (cap.get(2), cap.get(3)) {
+                let x = x.as_str().parse::<u32>().expect("at most 3 digits");
+                let y = y.as_str().parse::<u32>().expect("at most 3 digits");
+                result.push(Inst::Mul(x, y));
+            } else if cap.get(4).is_some() {
+                result.push(Inst::Do);
+            } else if cap.get(5).is_some() {
+                result.push(Inst::Dont);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        // part 1 doesn't know about do() and don't() yet, so only the muls count
+        data.iter()
+            .try_fold(0u32, |total, inst| match inst {
+                Inst::Mul(x, y) => total.checked_add(x * y),
+                Inst::Do | Inst::Dont => Some(total),
+            })
+            .ok_or_else(|| anyhow!("sum of products doesn't fit in a u32"))
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        let mut do_muls = true;
+        let mut result = 0;
+        for inst in data.iter() {
+            match inst {
+                Inst::Do => do_muls = true,
+                Inst::Dont => do_muls = false,
+                Inst::Mul(x, y) => {
+                    if do_muls {
+                        result = u32::checked_add(result, x * y)
+                            .ok_or_else(|| anyhow!("sum of products doesn't fit in a u32"))?;
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug)]
+pub enum Inst {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day03.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part1(&data).unwrap(), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part2(&data).unwrap(), 48);
+    }
+
+    #[test]
+    fn part1_ignores_do_and_dont() {
+        let data =
+            Day03::parse("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))")
+                .unwrap();
+        assert_eq!(Day03::part1(&data).unwrap(), 161);
+    }
+}
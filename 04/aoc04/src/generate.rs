@@ -0,0 +1,17 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// a `size` by `size` grid of X, M, A and S
+pub fn generate(seed: u64, size: usize) -> String {
+    const LETTERS: [char; 4] = ['X', 'M', 'A', 'S'];
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            out.push(LETTERS[rng.random_range(0..LETTERS.len())]);
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+extern crate anyhow;
+
+use aoc_common::{Cell, Colour, Direction8, Grid, ParseError, Part, Picture, Pos, Solution, Style};
+
+pub mod generate;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Grid<char>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, Some, "a letter")?;
+        if grid.width() == 0 {
+            return Err(ParseError::end_of_input("a grid of letters"));
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> anyhow::Result<u32> {
+        Ok(xmas_words(grid).len() as u32)
+    }
+
+    fn part2(grid: &Self::Input) -> anyhow::Result<u32> {
+        Ok(x_mas_centres(grid).count() as u32)
+    }
+
+    fn render(part: Part, grid: &Self::Input) -> anyhow::Result<Option<(u32, Picture)>> {
+        let (answer, found) = match part {
+            Part::One => {
+                let words = xmas_words(grid);
+                (words.len(), words.into_iter().flatten().collect())
+            }
+            // the centre of each X along with its four corners
+            Part::Two => {
+                let centres = x_mas_centres(grid).collect::<Vec<Pos>>();
+                let letters = centres
+                    .iter()
+                    .flat_map(|&pos| {
+                        let corners = [
+                            Direction8::NorthEast,
+                            Direction8::SouthEast,
+                            Direction8::SouthWest,
+                            Direction8::NorthWest,
+                        ]
+                        .map(|direction| grid.offset(pos, direction.delta()));
+                        corners.into_iter().flatten().chain([pos])
+                    })
+                    .collect::<HashSet<Pos>>();
+                (centres.len(), letters)
+            }
+        };
+
+        let found_style = Style::fg(Colour::Yellow).bold();
+        let picture = Picture::draw(grid, |pos, ch| {
+            let style = if found.contains(&pos) {
+                found_style
+            } else {
+                Style::fg(Colour::Grey)
+            };
+            Cell::new(*ch, style)
+        });
+        let meaning = match part {
+            Part::One => "part of an XMAS",
+            Part::Two => "part of an X-MAS",
+        };
+        let picture = picture.with_key(Cell::new('X', found_style), meaning);
+        Ok(Some((answer as u32, picture)))
+    }
+}
+
+/// the positions of every XMAS in the grid, in reading order, whichever way it runs
+fn xmas_words(grid: &Grid<char>) -> Vec<Vec<Pos>> {
+    let mut words = Vec::new();
+    for start in grid.positions().filter(|pos| grid[*pos] == 'X') {
+        for direction in Direction8::ALL {
+            let word = grid
+                .ray(start, direction.delta())
+                .take(4)
+                .collect::<Vec<Pos>>();
+            if word.iter().map(|pos| grid[*pos]).eq("XMAS".chars()) {
+                words.push(word);
+            }
+        }
+    }
+    words
+}
+
+/// every A in the middle of two diagonal MASes
+fn x_mas_centres(grid: &Grid<char>) -> impl Iterator<Item = Pos> + '_ {
+    let mut goal = vec!['M', 'A', 'S'];
+    goal.sort();
+
+    grid.positions()
+        .filter(|pos| grid[*pos] == 'A')
+        .filter(move |pos| {
+            // both diagonals through the A, each of which has to read MAS one way or the other
+            let line1 = get_line(grid, *pos, Direction8::SouthEast);
+            let line2 = get_line(grid, *pos, Direction8::NorthEast);
+
+            match (line1, line2) {
+                (Some(mut chrs1), Some(mut chrs2)) => {
+                    chrs1.sort();
+                    chrs2.sort();
+                    chrs1 == goal && chrs2 == goal
+                }
+                _ => false,
+            }
+        })
+}
+
+/// the letters either side of `pos` along with the one at it, if both sides are on the grid
+fn get_line(grid: &Grid<char>, pos: Pos, direction: Direction8) -> Option<Vec<char>> {
+    let start = grid.offset(pos, direction.opposite().delta())?;
+    let end = grid.offset(pos, direction.delta())?;
+    Some(vec![grid[start], grid[pos], grid[end]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day04.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&data).unwrap(), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part2(&data).unwrap(), 9);
+    }
+
+    #[test]
+    fn render_highlights_every_match() {
+        let data = Day04::parse(EXAMPLE).unwrap();
+        let (answer, _) = Day04::render(Part::One, &data).unwrap().unwrap();
+        assert_eq!(answer, 18);
+        let (answer, picture) = Day04::render(Part::Two, &data).unwrap().unwrap();
+        assert_eq!(answer, 9);
+        let highlighted = picture.cells.iter().filter(|(_, cell)| cell.style.bold);
+        // the 9 X-MASes have 45 letters between them, but some are shared
+        assert_eq!(highlighted.count(), 35);
+        assert_eq!(picture.to_string().lines().next(), EXAMPLE.lines().next());
+    }
+
+    #[test]
+    fn empty_grid_is_rejected() {
+        assert!(Day04::parse("").is_err());
+        assert!(Day04::parse("\n\n").is_err());
+    }
+}
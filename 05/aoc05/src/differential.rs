@@ -0,0 +1,71 @@
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+
+use super::Day05;
+
+/// a hidden page order, with updates drawn from it in any order
+fn puzzle() -> impl Strategy<Value = (Vec<u32>, Vec<Vec<u32>>)> {
+    prop::sample::subsequence((10..100).collect::<Vec<u32>>(), 1..8)
+        .prop_shuffle()
+        .prop_flat_map(|order| {
+            let update = prop::sample::subsequence(order.clone(), 1..=order.len()).prop_shuffle();
+            (Just(order), prop::collection::vec(update, 1..8))
+        })
+}
+
+/// a rule for every pair of pages, like the real input has
+fn to_input(order: &[u32], updates: &[Vec<u32>]) -> String {
+    let mut out = String::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            out.push_str(&format!("{}|{}\n", before, after));
+        }
+    }
+    out.push('\n');
+    for update in updates {
+        let pages = update.iter().map(u32::to_string).collect::<Vec<String>>();
+        out.push_str(&format!("{}\n", pages.join(",")));
+    }
+    out
+}
+
+fn rule_exists(rules: &[(u32, u32)], before: u32, after: u32) -> bool {
+    rules.contains(&(before, after))
+}
+
+/// in order if no later page has a rule saying it belongs before an earlier one
+fn is_ordered(rules: &[(u32, u32)], update: &[u32]) -> bool {
+    (0..update.len())
+        .all(|i| (i + 1..update.len()).all(|j| !rule_exists(rules, update[j], update[i])))
+}
+
+/// with a rule for every pair, a page's place is how many others have to come before it
+fn reorder(rules: &[(u32, u32)], update: &[u32]) -> Vec<u32> {
+    let mut ordered = update.to_vec();
+    ordered.sort_by_key(|page| {
+        update
+            .iter()
+            .filter(|other| rule_exists(rules, **other, *page))
+            .count()
+    });
+    ordered
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force((order, updates) in puzzle()) {
+        let data = Day05::parse(&to_input(&order, &updates)).unwrap();
+        let rules = data.0.iter().map(|r| (r.before, r.after)).collect::<Vec<(u32, u32)>>();
+
+        let (ordered, unordered): (Vec<&Vec<u32>>, Vec<&Vec<u32>>) =
+            updates.iter().partition(|update| is_ordered(&rules, update));
+        let middles = ordered.iter().map(|update| update[update.len() / 2]).sum::<u32>();
+        prop_assert_eq!(Day05::part1(&data).unwrap(), middles);
+        let middles = unordered
+            .iter()
+            .map(|update| reorder(&rules, update)[update.len() / 2])
+            .sum::<u32>();
+        prop_assert_eq!(Day05::part2(&data).unwrap(), middles);
+    }
+}
@@ -0,0 +1,41 @@
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+/// rules for every pair of `size` pages following one hidden order, then `size` updates
+/// of an odd number of those pages, some of them already in order
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let count = size.clamp(3, 90);
+
+    // the hidden order the rules all agree with
+    let mut order = (10..100u32).collect::<Vec<u32>>();
+    order.shuffle(&mut rng);
+    order.truncate(count);
+
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rules.shuffle(&mut rng);
+
+    let mut out = rules.join("\n");
+    out.push_str("\n\n");
+    for _ in 0..size.max(1) {
+        let len = rng.random_range(1..=count.min(23).div_ceil(2)) * 2 - 1;
+        let mut update = order
+            .choose_multiple(&mut rng, len)
+            .copied()
+            .collect::<Vec<u32>>();
+        if rng.random_bool(0.5) {
+            let rank = |page: &u32| order.iter().position(|p| p == page);
+            update.sort_by_key(rank);
+        }
+        let update = update.iter().map(u32::to_string).collect::<Vec<String>>();
+        out.push_str(&update.join(","));
+        out.push('\n');
+    }
+    out
+}
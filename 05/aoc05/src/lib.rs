@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+extern crate anyhow;
+use anyhow::{anyhow, bail};
+
+use aoc_common::{parse_num, ParseError, Solution};
+use tracing::debug;
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = (Vec<Rule>, Vec<Vec<u32>>);
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+
+        // rules come first, up until a blank line
+        let mut rules = Vec::new();
+        let mut found_blank = false;
+        for (i, line) in lines.by_ref() {
+            if line.is_empty() {
+                found_blank = true;
+                break;
+            }
+            let Some((before, after)) = line.split_once('|') else {
+                return Err(ParseError::at(i, line, line, "`a|b` rule"));
+            };
+            rules.push(Rule {
+                before: parse_num(i, line, before)?,
+                after: parse_num(i, line, after)?,
+            });
+        }
+        if !found_blank {
+            return Err(ParseError::end_of_input("a blank line followed by updates"));
+        }
+
+        // then everything else is updates
+        let updates = lines
+            .map(|(i, line)| {
+                line.split(',')
+                    .map(|n| parse_num(i, line, n))
+                    .collect::<Result<Vec<u32>, _>>()
+            })
+            .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+        Ok((rules, updates))
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        let (rules, updates) = data;
+        let pages = build_pages(rules);
+
+        // check updates one at a time
+        let mut mid_sum = 0;
+        for (number, update) in (1..).zip(updates) {
+            if is_ordered(number, update, &pages) {
+                mid_sum = add_page(mid_sum, update[update.len() / 2])?;
+            }
+        }
+
+        Ok(mid_sum)
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        let (rules, updates) = data;
+        let pages = build_pages(rules);
+
+        // check updates one at a time
+        let incorrect_updates = (1..)
+            .zip(updates)
+            .filter(|(number, update)| !is_ordered(*number, update, &pages))
+            .map(|(_, update)| update.clone())
+            .collect::<Vec<Vec<u32>>>();
+
+        // now we have a vector of updates that need to be properly ordered
+        let mut mid_sum = 0;
+        for mut update in incorrect_updates {
+            // treat the incorrect update as a queue and keep popping until we find
+            // the right value for the next slot
+
+            let mut ordered_update = Vec::new();
+            // how many pages in a row have been sent to the back, which can only reach the
+            // length of the queue if the rules go round in a circle
+            let mut deferred = 0;
+            'order_loop: while !update.is_empty() {
+                if deferred == update.len() {
+                    bail!("rules for pages {:?} contradict each other", update);
+                }
+                let cur = update.remove(0);
+                for other in update.iter() {
+                    if let Some(page) = pages.get(other) {
+                        if page.comes_before.contains(&cur) {
+                            // if cur can't come next, put it on the end of the queue
+                            update.push(cur);
+                            deferred += 1;
+                            continue 'order_loop;
+                        }
+                    }
+                }
+                // otherwise, it comes next
+                ordered_update.push(cur);
+                deferred = 0;
+            }
+
+            mid_sum = add_page(mid_sum, ordered_update[ordered_update.len() / 2])?;
+        }
+
+        Ok(mid_sum)
+    }
+}
+
+fn add_page(mid_sum: u32, page: u32) -> anyhow::Result<u32> {
+    mid_sum
+        .checked_add(page)
+        .ok_or_else(|| anyhow!("sum of middle pages doesn't fit in a u32"))
+}
+
+/// construct graph of page rules
+fn build_pages(rules: &[Rule]) -> HashMap<u32, Page> {
+    let mut pages = HashMap::new();
+    for rule in rules {
+        let before = pages.entry(rule.before).or_insert(Page::new());
+        before.comes_before.push(rule.after);
+
+        let after = pages.entry(rule.after).or_insert(Page::new());
+        after.comes_after.push(rule.before);
+    }
+
+    pages
+}
+
+/// whether an update follows every rule, logging the first one it breaks. `number`
+/// counts updates from 1 and is only for the log
+fn is_ordered(number: usize, update: &[u32], pages: &HashMap<u32, Page>) -> bool {
+    let mut update = update.to_vec();
+    let mut came_after: Vec<u32> = Vec::new(); // all pages that come after the current page in the current update
+    while let Some(cur) = update.pop() {
+        for successor in came_after.iter() {
+            // search for contradictions
+            if let Some(page) = pages.get(successor) {
+                if page.comes_before.contains(&cur) {
+                    debug!("update {} violates rule {}|{}", number, successor, cur);
+                    return false;
+                }
+            }
+        }
+        came_after.push(cur);
+    }
+
+    true
+}
+
+pub struct Rule {
+    before: u32,
+    after: u32,
+}
+struct Page {
+    comes_before: Vec<u32>,
+    comes_after: Vec<u32>,
+}
+impl Page {
+    fn new() -> Self {
+        Page {
+            comes_after: Default::default(),
+            comes_before: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day05.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&data).unwrap(), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part2(&data).unwrap(), 123);
+    }
+
+    #[test]
+    fn contradictory_rules_are_an_error() {
+        let data = Day05::parse("1|2\n2|1\n\n2,1\n").unwrap();
+        assert!(Day05::part2(&data).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+
+use super::Day06;
+
+/// small labs with scattered obstacles and a guard facing any way
+fn lab() -> impl Strategy<Value = Vec<Vec<char>>> {
+    (1usize..8, 1usize..8)
+        .prop_flat_map(|(width, height)| {
+            (
+                prop::collection::vec(
+                    prop::collection::vec(prop::bool::weighted(0.2), width),
+                    height,
+                ),
+                0..height,
+                0..width,
+                prop::sample::select(vec!['^', '>', 'v', '<']),
+            )
+        })
+        .prop_map(|(obstacles, y, x, guard)| {
+            let mut lab = obstacles
+                .into_iter()
+                .map(|row| row.into_iter().map(|o| if o { '#' } else { '.' }).collect())
+                .collect::<Vec<Vec<char>>>();
+            lab[y][x] = guard;
+            lab
+        })
+}
+
+/// every cell the guard stands on before walking off, or `None` if it never does
+///
+/// nothing clever, just walk one step at a time and give up once there have been more
+/// steps than there are ways to stand in the lab
+fn walk(lab: &[Vec<char>]) -> Option<HashSet<(i32, i32)>> {
+    let (height, width) = (lab.len() as i32, lab[0].len() as i32);
+    let (mut x, mut y, mut facing) = lab
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, c)| (x, y, *c)))
+        .find_map(|(x, y, c)| "^>v<".find(c).map(|facing| (x as i32, y as i32, facing)))
+        .unwrap();
+    let mut visited = HashSet::from([(x, y)]);
+    for _ in 0..4 * width * height + 4 {
+        let (dx, dy) = [(0, -1), (1, 0), (0, 1), (-1, 0)][facing];
+        let (nx, ny) = (x + dx, y + dy);
+        if nx < 0 || nx >= width || ny < 0 || ny >= height {
+            return Some(visited);
+        }
+        if lab[ny as usize][nx as usize] == '#' {
+            facing = (facing + 1) % 4;
+        } else {
+            (x, y) = (nx, ny);
+            visited.insert((x, y));
+        }
+    }
+    None
+}
+
+/// try an obstacle on every empty cell except where the guard starts
+fn loop_count(lab: &[Vec<char>]) -> u32 {
+    let mut count = 0;
+    for y in 0..lab.len() {
+        for x in 0..lab[0].len() {
+            if lab[y][x] == '.' {
+                let mut blocked = lab.to_vec();
+                blocked[y][x] = '#';
+                if walk(&blocked).is_none() {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force(lab in lab()) {
+        let input = lab.iter().map(|row| row.iter().collect::<String>() + "\n").collect::<String>();
+        let data = Day06::parse(&input).unwrap();
+
+        match walk(&lab) {
+            Some(visited) => {
+                prop_assert_eq!(Day06::part1(&data).unwrap(), visited.len() as u32);
+                prop_assert_eq!(Day06::part2(&data).unwrap(), loop_count(&lab));
+            }
+            None => {
+                prop_assert!(Day06::part1(&data).is_err());
+                prop_assert!(Day06::part2(&data).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use aoc_common::Solution;
+
+use super::{get_guard_path, Day06};
+
+/// a `size` by `size` lab with scattered obstacles and a guard that eventually walks out
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    // real maps never trap the guard, so keep rolling until one doesn't
+    for _ in 0..100 {
+        let map = random_map(&mut rng, size);
+        if escapes(&map) {
+            return map;
+        }
+    }
+
+    // a map without obstacles always lets the guard out
+    let mut out = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            out.push(if (x, y) == (size / 2, size / 2) {
+                '^'
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn random_map(rng: &mut StdRng, size: usize) -> String {
+    let guard = (rng.random_range(0..size), rng.random_range(0..size));
+    let mut out = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            out.push(if (x, y) == guard {
+                '^'
+            } else if rng.random_bool(0.1) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// whether the guard leaves the map rather than going round in circles
+fn escapes(map: &str) -> bool {
+    let (board, guard) = Day06::parse(map).expect("generated maps parse");
+    get_guard_path(&board, guard).is_ok()
+}
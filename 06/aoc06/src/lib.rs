@@ -0,0 +1,377 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+extern crate anyhow;
+use anyhow::bail;
+
+use aoc_common::{Cell, Colour, Direction4, Grid, ParseError, Part, Picture, Pos, Solution, Style};
+use tracing::debug;
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = (Board, Guard);
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut guard = None;
+        let board = Grid::parse_with(input, |pos, chr| {
+            let direction = match chr {
+                '.' => return Ok(Space::Empty),
+                '#' => return Ok(Space::Obstacle),
+                '^' => Direction4::North,
+                '>' => Direction4::East,
+                'v' => Direction4::South,
+                '<' => Direction4::West,
+                _ => return Err(String::from("`.`, `#` or a guard")),
+            };
+            if guard.is_some() {
+                return Err(String::from("only one guard"));
+            }
+            guard = Some(Guard { pos, direction });
+            Ok(Space::Empty)
+        })?;
+
+        let Some(guard) = guard else {
+            return Err(ParseError::end_of_input("a guard (`^`, `>`, `v` or `<`)"));
+        };
+
+        Ok((board, guard))
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        let (board, guard) = data;
+        Ok(get_guard_path(board, guard.clone())?.len() as u32)
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        let (board, guard) = data;
+        Ok(get_loop_obstacles(board, guard)?.len() as u32)
+    }
+
+    fn render(part: Part, data: &Self::Input) -> anyhow::Result<Option<(u32, Picture)>> {
+        let (board, guard) = data;
+        let visits = get_guard_visits(board, guard.clone())?;
+        let obstacles = match part {
+            Part::One => HashSet::new(),
+            Part::Two => get_loop_obstacles(board, guard)?.into_iter().collect(),
+        };
+        let answer = match part {
+            Part::One => visits.len(),
+            Part::Two => obstacles.len(),
+        };
+
+        // a heatmap of the path, since the guard can cross a spot once each way it faces
+        let heat = |times: u32| Colour::ramp(Colour::Blue, Colour::Red, times - 1, 3);
+        let start = Cell::new(guard.symbol(), Style::fg(Colour::White).on(heat(1)).bold());
+        let new_obstacle = Cell::new('O', Style::fg(Colour::Magenta).bold());
+        let picture = Picture::draw(board, |pos, space| {
+            if pos == guard.pos {
+                Cell::new(start.ch, start.style.on(heat(visits[&pos])))
+            } else if obstacles.contains(&pos) {
+                Cell::new('O', new_obstacle.style.on(heat(visits[&pos])))
+            } else if let Some(times) = visits.get(&pos) {
+                Cell::new('X', Style::PLAIN.on(heat(*times)))
+            } else if *space == Space::Obstacle {
+                Cell::new('#', Style::fg(Colour::White))
+            } else {
+                Cell::new('.', Style::fg(Colour::Grey))
+            }
+        });
+
+        let picture = picture.with_key(start, "where the guard starts").with_key(
+            Cell::new('X', Style::PLAIN.on(heat(1))),
+            "where the guard walks, redder the more often",
+        );
+        let picture = match part {
+            Part::One => picture,
+            Part::Two => picture.with_key(new_obstacle, "an obstacle that traps the guard"),
+        };
+        Ok(Some((answer as u32, picture)))
+    }
+}
+
+/// every spot where one more obstacle would trap the guard in a loop
+fn get_loop_obstacles(board: &Board, guard: &Guard) -> anyhow::Result<Vec<Pos>> {
+    let mut board = board.clone();
+    let mut obstacles = Vec::new();
+
+    // find all the spots the guard would walk unobstructed
+    let guard_path = get_guard_path(&board, guard.clone())?;
+
+    // try inserting an obstacle at each path, except where the guard is standing
+    for spot in guard_path.into_iter().filter(|spot| *spot != guard.pos) {
+        board[spot] = Space::Obstacle;
+        if is_loopy(&board, guard.clone()) {
+            debug!(
+                "an obstacle at ({}, {}) traps the guard in a loop",
+                spot.x, spot.y
+            );
+            obstacles.push(spot);
+        }
+
+        // clean up the board to use it again
+        board[spot] = Space::Empty;
+    }
+
+    Ok(obstacles)
+}
+
+fn get_guard_path(board: &Board, guard: Guard) -> anyhow::Result<HashSet<Pos>> {
+    Ok(get_guard_visits(board, guard)?.into_keys().collect())
+}
+
+/// how many times the guard passes through each spot on the way off the map
+fn get_guard_visits(board: &Board, mut guard: Guard) -> anyhow::Result<HashMap<Pos, u32>> {
+    // keep track of positions we've touched, and which way we were facing, since coming
+    // back the same way means the guard never leaves
+    let mut visits = HashMap::new();
+    let mut states = HashSet::new();
+    visits.insert(guard.pos, 1);
+    states.insert(guard.clone());
+
+    loop {
+        if !turn_to_open_space(board, &mut guard) {
+            bail!("the guard is boxed in by obstacles");
+        }
+        // execute until the next move is off the board, checking after turning since
+        // that can leave the guard facing the edge
+        let Some(next) = guard.get_facing_pos(board) else {
+            break;
+        };
+        guard.pos = next;
+        *visits.entry(guard.pos).or_insert(0) += 1;
+        if !states.insert(guard.clone()) {
+            bail!("the guard walks in a loop and never leaves the map");
+        }
+    }
+
+    Ok(visits)
+}
+
+fn is_loopy(board: &Board, mut guard: Guard) -> bool {
+    // keep track of positions we've touched
+    let mut seen = HashSet::new();
+    seen.insert(guard.clone());
+
+    loop {
+        // spinning on the spot forever counts as a loop too
+        if !turn_to_open_space(board, &mut guard) {
+            return true;
+        }
+        // execute until the next move is off the board
+        let Some(next) = guard.get_facing_pos(board) else {
+            return false;
+        };
+        guard.pos = next;
+
+        // if we've been here before, we're in a loop
+        if seen.contains(&guard) {
+            return true;
+        }
+        seen.insert(guard.clone());
+    }
+}
+
+/// one step of the guard's patrol. it only says what changed, so that keeping lots of
+/// them around stays cheap on a big map
+#[derive(Clone, Debug)]
+pub struct Patrol {
+    /// how many turns and steps forward the guard has taken to get here
+    pub step: usize,
+    pub guard: Guard,
+    /// whether the guard turned on the spot to get here, rather than stepping forward
+    pub turned: bool,
+    /// whether the guard hasn't been here before
+    pub first_visit: bool,
+    /// how many spots the guard has been to so far, this one included
+    pub visited: usize,
+}
+
+/// the guard's patrol one turn or step at a time, starting from where they stand. it ends
+/// once the guard is about to leave the map, or would start going round in circles
+pub fn patrol<'a>(board: &'a Board, guard: &Guard) -> impl Iterator<Item = Patrol> + 'a {
+    let mut visited = board.map(|_| false);
+    visited[guard.pos] = true;
+    let mut states = HashSet::from([guard.clone()]);
+    let mut next = Some(Patrol {
+        step: 0,
+        guard: guard.clone(),
+        turned: false,
+        first_visit: true,
+        visited: 1,
+    });
+
+    iter::from_fn(move || {
+        let current = next.take()?;
+        let mut guard = current.guard.clone();
+        let turned = match guard.get_facing_pos(board) {
+            None => return Some(current),
+            Some(ahead) if board[ahead] == Space::Obstacle => {
+                guard.turn_right();
+                true
+            }
+            Some(ahead) => {
+                guard.pos = ahead;
+                false
+            }
+        };
+        let first_visit = !turned && !visited[guard.pos];
+        visited[guard.pos] = true;
+        next = Some(Patrol {
+            step: current.step + 1,
+            guard,
+            turned,
+            first_visit,
+            visited: current.visited + usize::from(first_visit),
+        })
+        .filter(|patrol| states.insert(patrol.guard.clone()));
+        Some(current)
+    })
+}
+
+/// if we're facing an obstacle, turn right until we aren't, or give up if there are
+/// obstacles all the way round
+fn turn_to_open_space(board: &Board, guard: &mut Guard) -> bool {
+    for _ in 0..4 {
+        match guard.get_facing_pos(board).map(|pos| board[pos]) {
+            Some(Space::Obstacle) => guard.turn_right(),
+            _ => return true,
+        }
+    }
+    false
+}
+
+/// the lab, with the guard's starting spot left empty
+pub type Board = Grid<Space>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Space {
+    Empty,
+    Obstacle,
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pos: Pos,
+    direction: Direction4,
+}
+impl Guard {
+    pub fn pos(&self) -> Pos {
+        self.pos
+    }
+    pub fn direction(&self) -> Direction4 {
+        self.direction
+    }
+    /// the spot in front of the guard, or `None` if that's off the board
+    fn get_facing_pos(&self, board: &Board) -> Option<Pos> {
+        board.offset(self.pos, self.direction.delta())
+    }
+    fn turn_right(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+    /// how the guard is drawn on the map
+    pub fn symbol(&self) -> char {
+        match self.direction {
+            Direction4::North => '^',
+            Direction4::East => '>',
+            Direction4::South => 'v',
+            Direction4::West => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day06.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&data).unwrap(), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part2(&data).unwrap(), 6);
+    }
+
+    #[test]
+    fn render_marks_the_path_and_new_obstacles() {
+        let data = Day06::parse(EXAMPLE).unwrap();
+        let (answer, picture) = Day06::render(Part::Two, &data).unwrap().unwrap();
+        assert_eq!(answer, 6);
+        let text = picture.to_string();
+        let count = |ch| {
+            text.lines()
+                .take(10)
+                .flat_map(str::chars)
+                .filter(|c| *c == ch)
+                .count()
+        };
+        assert_eq!(count('O'), 6);
+        // the start isn't drawn as walked, and neither are the new obstacles on the path
+        assert_eq!(count('X') + count('O') + count('^'), 41);
+
+        // the path crosses itself, so some spots are walked through more than once
+        let visits = get_guard_visits(&data.0, data.1.clone()).unwrap();
+        assert_eq!(visits.len(), 41);
+        assert!(visits.values().any(|times| *times > 1));
+    }
+
+    #[test]
+    fn patrol_covers_the_same_path() {
+        let (board, guard) = Day06::parse(EXAMPLE).unwrap();
+        let steps = patrol(&board, &guard).collect::<Vec<Patrol>>();
+        assert_eq!(steps.last().unwrap().visited, 41);
+        assert_eq!(steps.iter().filter(|step| step.first_visit).count(), 41);
+        assert!(steps[0].first_visit && steps[0].guard.pos() == guard.pos());
+        assert!(steps.iter().enumerate().all(|(i, step)| step.step == i));
+        // the guard turns 10 times on the way out of the example lab
+        assert_eq!(steps.iter().filter(|step| step.turned).count(), 10);
+
+        // a patrol that would go on forever stops before repeating itself
+        let (board, guard) = Day06::parse(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert!(patrol(&board, &guard).count() < 4 * 16);
+    }
+
+    #[test]
+    fn turn_right_goes_all_the_way_round() {
+        let mut guard = Guard {
+            pos: Pos::new(0, 0),
+            direction: Direction4::North,
+        };
+        for expected in [
+            Direction4::East,
+            Direction4::South,
+            Direction4::West,
+            Direction4::North,
+        ] {
+            guard.turn_right();
+            assert_eq!(guard.direction, expected);
+        }
+        assert_eq!(guard.pos, Pos::new(0, 0));
+    }
+
+    #[test]
+    fn guard_that_never_leaves_is_an_error() {
+        let data = Day06::parse(".#.\n#^#\n.#.\n").unwrap();
+        assert!(Day06::part1(&data).is_err());
+        let data = Day06::parse(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert!(Day06::part1(&data).is_err());
+    }
+
+    #[test]
+    fn turning_to_face_the_edge_walks_straight_off() {
+        let data = Day06::parse("v\n#\n").unwrap();
+        assert_eq!(Day06::part1(&data).unwrap(), 1);
+    }
+}
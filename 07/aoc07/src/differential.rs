@@ -0,0 +1,70 @@
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+
+use super::Day07;
+
+/// whether any of the `operators.pow(n - 1)` ways of filling in the operators works,
+/// trying every single one
+fn can_make(test_value: i64, numbers: &[i64], operators: u32) -> bool {
+    let slots = numbers.len() as u32 - 1;
+    (0..operators.pow(slots)).any(|mut choice| {
+        let mut total = numbers[0] as i128;
+        for &n in &numbers[1..] {
+            total = match choice % operators {
+                0 => total + n as i128,
+                1 => total * n as i128,
+                _ => format!("{}{}", total, n).parse().unwrap(),
+            };
+            choice /= operators;
+        }
+        total == test_value as i128
+    })
+}
+
+/// equations that are possible about half the time, by working some test values out
+/// from random operators
+fn equation() -> impl Strategy<Value = (i64, Vec<i64>)> {
+    (
+        prop::collection::vec(0i64..20, 1..7),
+        prop::collection::vec(0..3, 6),
+        prop::option::of(1i64..10_000),
+    )
+        .prop_map(|(numbers, operators, test_value)| {
+            let test_value = test_value.unwrap_or_else(|| {
+                numbers[1..]
+                    .iter()
+                    .zip(operators)
+                    .fold(numbers[0], |total, (&n, op)| match op {
+                        0 => total + n,
+                        1 => total * n,
+                        _ => format!("{}{}", total, n).parse().unwrap(),
+                    })
+            });
+            (test_value, numbers)
+        })
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force(equations in prop::collection::vec(equation(), 0..10)) {
+        let input = equations
+            .iter()
+            .map(|(test_value, numbers)| {
+                let numbers = numbers.iter().map(i64::to_string).collect::<Vec<String>>();
+                format!("{}: {}\n", test_value, numbers.join(" "))
+            })
+            .collect::<String>();
+        let data = Day07::parse(&input).unwrap();
+
+        let total = |operators| {
+            equations
+                .iter()
+                .filter(|(test_value, numbers)| can_make(*test_value, numbers, operators))
+                .map(|(test_value, _)| test_value)
+                .sum::<i64>()
+        };
+        prop_assert_eq!(Day07::part1(&data).unwrap(), total(2));
+        prop_assert_eq!(Day07::part2(&data).unwrap(), total(3));
+    }
+}
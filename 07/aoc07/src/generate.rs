@@ -0,0 +1,33 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` calibration equations of 2 to 6 numbers, about half of which some mix of `+`,
+/// `*` and `||` can make
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let len = rng.random_range(2..=6);
+        let numbers = (0..len)
+            .map(|_| rng.random_range(1..100i64))
+            .collect::<Vec<i64>>();
+
+        let test_value = if rng.random_bool(0.5) {
+            // work one out, left to right like the elephants do
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |total, &n| match rng.random_range(0..3) {
+                    0 => total + n,
+                    1 => total * n,
+                    _ => format!("{}{}", total, n).parse().expect("fits in an i64"),
+                })
+        } else {
+            rng.random_range(1..1_000_000)
+        };
+
+        let numbers = numbers.iter().map(i64::to_string).collect::<Vec<String>>();
+        out.push_str(&format!("{}: {}\n", test_value, numbers.join(" ")));
+    }
+    out
+}
@@ -0,0 +1,229 @@
+extern crate anyhow;
+use anyhow::{anyhow, bail};
+
+use aoc_common::{parse_num, ParseError, Solution};
+use tracing::{debug, enabled, Level};
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+/// bridge calibration. the numbers in an equation can't be negative, since there's no
+/// sensible way to put a negative number on the end of another with `||`
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<Equation>;
+    type Output = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let Some((test_value, coefficients)) = line.split_once(':') else {
+                    return Err(ParseError::at(i, line, line, "`value: numbers` equation"));
+                };
+                let test_value = parse_num(i, line, test_value)?;
+                let coefficients = coefficients
+                    .split_whitespace()
+                    .map(|val| {
+                        let n = parse_num(i, line, val)?;
+                        if n < 0 {
+                            return Err(ParseError::at(
+                                i,
+                                line,
+                                val,
+                                "a number that isn't negative",
+                            ));
+                        }
+                        Ok(n)
+                    })
+                    .collect::<Result<Vec<i64>, _>>()?;
+                if coefficients.is_empty() {
+                    return Err(ParseError::at(
+                        i,
+                        line,
+                        line,
+                        "at least one number after `:`",
+                    ));
+                }
+                Ok(Equation {
+                    test_value,
+                    coefficients,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<i64> {
+        calibration_total(data, false)
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<i64> {
+        calibration_total(data, true)
+    }
+}
+
+/// how many steps the search for a single equation gets before giving up, so inputs
+/// with long runs of 1s fail rather than take forever
+const STEP_BUDGET: u32 = 10_000_000;
+
+/// sum the test values of every equation that some combination of operators can make
+fn calibration_total(data: &[Equation], allow_concat: bool) -> anyhow::Result<i64> {
+    let mut output = 0i64;
+    for (number, equation) in (1..).zip(data) {
+        if ways_to_make(equation, allow_concat, 1)? > 0 {
+            if enabled!(Level::DEBUG) {
+                // finding every way is slower than finding one, so only count them for the log
+                match ways_to_make(equation, allow_concat, u64::MAX) {
+                    Ok(ways) => debug!(
+                        "equation {} solvable by {} operator combinations",
+                        number, ways
+                    ),
+                    Err(_) => debug!(
+                        "equation {} solvable by too many operator combinations to count",
+                        number
+                    ),
+                }
+            }
+            output = output
+                .checked_add(equation.test_value)
+                .ok_or_else(|| anyhow!("calibration total doesn't fit in an i64"))?;
+        }
+    }
+    Ok(output)
+}
+
+/// how many combinations of operators make an equation's test value, stopping once
+/// `wanted` have been found
+fn ways_to_make(equation: &Equation, allow_concat: bool, wanted: u64) -> anyhow::Result<u64> {
+    let mut steps = 0;
+    count_ways(
+        &equation.coefficients[1..],
+        equation.test_value,
+        Some(equation.coefficients[0]),
+        allow_concat,
+        wanted,
+        &mut steps,
+    )
+}
+
+/// the same for the coefficients still to go, given the total so far, which is `None`
+/// once it's gone past what an i64 holds
+fn count_ways(
+    coefficients: &[i64],
+    test_value: i64,
+    running_total: Option<i64>,
+    allow_concat: bool,
+    wanted: u64,
+    steps: &mut u32,
+) -> anyhow::Result<u64> {
+    *steps += 1;
+    if *steps > STEP_BUDGET {
+        bail!(
+            "gave up on the equation for {} after {} steps",
+            test_value,
+            STEP_BUDGET
+        );
+    }
+
+    // with no zeros left, every operator makes the total bigger or keeps it the same,
+    // so there's no coming back from overshooting. a zero can still take it back to 0
+    let overshot = running_total.is_none_or(|total| total > test_value);
+    if overshot && !coefficients.contains(&0) {
+        return Ok(0);
+    }
+
+    // base case: no more coefficients to operate on, so check if we made the test value
+    let Some((&next, rest)) = coefficients.split_first() else {
+        return Ok(u64::from(running_total == Some(test_value)));
+    };
+
+    // recursive case: try each operation. an overflowed total only ever gets bigger,
+    // unless it's multiplied by 0
+    let multiplied = match running_total {
+        Some(total) => total.checked_mul(next),
+        None => (next == 0).then_some(0),
+    };
+    let added = running_total.and_then(|total| total.checked_add(next));
+    let concated = allow_concat.then(|| {
+        // 0 takes up a digit like any other number
+        let digits = next.checked_ilog10().unwrap_or(0) + 1;
+        10i64
+            .checked_pow(digits)
+            .zip(running_total)
+            .and_then(|(shift, total)| total.checked_mul(shift))
+            .and_then(|total| total.checked_add(next))
+    });
+    let mut found = 0;
+    for total in [Some(multiplied), Some(added), concated]
+        .into_iter()
+        .flatten()
+    {
+        found += count_ways(rest, test_value, total, allow_concat, wanted - found, steps)?;
+        if found == wanted {
+            break;
+        }
+    }
+
+    Ok(found)
+}
+
+#[derive(Debug, Clone)]
+pub struct Equation {
+    test_value: i64,
+    coefficients: Vec<i64>,
+}
+impl Equation {
+    pub fn test_value(&self) -> i64 {
+        self.test_value
+    }
+    pub fn coefficients(&self) -> &[i64] {
+        &self.coefficients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day07.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part1(&data).unwrap(), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part2(&data).unwrap(), 11387);
+    }
+
+    #[test]
+    fn overflow_and_long_searches_are_handled() {
+        let data = Day07::parse("9223372036854775807: 9223372036854775807 2 999\n").unwrap();
+        assert_eq!(Day07::part2(&data).unwrap(), 0);
+        let ones = vec!["1"; 40].join(" ");
+        let data = Day07::parse(&format!("1000: {}\n", ones)).unwrap();
+        assert!(Day07::part2(&data).is_err());
+    }
+
+    #[test]
+    fn zeros_can_bring_an_overshot_total_back() {
+        // 5 * 3 overshoots, but * 0 + 2 gets back to 2
+        let data = Day07::parse("2: 5 3 0 2\n10: 0 10\n").unwrap();
+        assert_eq!(Day07::part1(&data).unwrap(), 12);
+        // as does a total too big for an i64
+        let data = Day07::parse("7: 9223372036854775807 9 0 7\n").unwrap();
+        assert_eq!(Day07::part2(&data).unwrap(), 7);
+        // and 0 is a digit to put on the end
+        let data = Day07::parse("120: 12 0\n").unwrap();
+        assert_eq!(Day07::part2(&data).unwrap(), 120);
+        assert!(Day07::parse("10: -1 10\n").is_err());
+    }
+}
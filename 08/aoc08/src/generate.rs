@@ -0,0 +1,23 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// a `size` by `size` map with a handful of antennas on a few frequencies
+pub fn generate(seed: u64, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let frequencies = rng.random_range(1..=(size / 4).clamp(1, FREQUENCIES.len()));
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            if rng.random_bool(0.05) {
+                out.push(FREQUENCIES[rng.random_range(0..frequencies)] as char);
+            } else {
+                out.push('.');
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
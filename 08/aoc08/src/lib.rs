@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+extern crate anyhow;
+
+use aoc_common::{Cell, Colour, Grid, ParseError, Part, Picture, Pos, Solution, Style, Vec2};
+
+pub mod generate;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = (Grid<char>, Antennas);
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, Some, "a map of antennas")?;
+        if grid.height() == 0 {
+            return Err(ParseError::end_of_input("a map of antennas"));
+        }
+
+        let mut antennas: Antennas = HashMap::new();
+        for (pos, ch) in grid.iter().filter(|(_pos, ch)| **ch != '.') {
+            antennas.entry(*ch).or_default().push(pos);
+        }
+
+        Ok((grid, antennas))
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        Ok(get_antinodes(data, false)?.len() as u32)
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        Ok(get_antinodes(data, true)?.len() as u32)
+    }
+
+    fn render(part: Part, data: &Self::Input) -> anyhow::Result<Option<(u32, Picture)>> {
+        let (grid, freq_map) = data;
+        let antinodes = get_antinodes(data, part == Part::Two)?;
+
+        // each frequency gets a colour of its own, in a stable order
+        let colours = freq_map
+            .keys()
+            .sorted()
+            .zip(Colour::PALETTE.iter().cycle())
+            .map(|(frequency, colour)| (*frequency, *colour))
+            .collect::<HashMap<char, Colour>>();
+
+        let picture = Picture::draw(grid, |pos, ch| match (*ch, antinodes.get(&pos)) {
+            ('.', None) => Cell::new('.', Style::fg(Colour::Grey)),
+            ('.', Some(frequency)) => Cell::new('#', Style::fg(colours[frequency])),
+            (antenna, None) => Cell::new(antenna, Style::fg(colours[&antenna]).bold()),
+            (antenna, Some(_)) => Cell::new(
+                antenna,
+                Style::fg(colours[&antenna]).on(Colour::Grey).bold(),
+            ),
+        });
+
+        let picture = picture
+            .with_key(
+                Cell::new('a', Style::fg(Colour::PALETTE[0]).bold()),
+                "an antenna, coloured by frequency",
+            )
+            .with_key(
+                Cell::new('#', Style::fg(Colour::PALETTE[0])),
+                "an antinode, coloured by the first frequency making it",
+            )
+            .with_key(
+                Cell::new('a', Style::fg(Colour::PALETTE[0]).on(Colour::Grey).bold()),
+                "an antenna on an antinode",
+            );
+        Ok(Some((antinodes.len() as u32, picture)))
+    }
+}
+
+/// every antinode on the map, along with the lowest frequency that makes it. with
+/// `harmonics`, every point in line with a pair of antennas counts, not just the ones
+/// twice as far from one as the other
+fn get_antinodes(
+    data: &(Grid<char>, Antennas),
+    harmonics: bool,
+) -> anyhow::Result<HashMap<Pos, char>> {
+    let mut antinodes = HashMap::new();
+    let (grid, freq_map) = data;
+
+    for (frequency, positions) in freq_map.iter().sorted_by_key(|(frequency, _)| **frequency) {
+        for (pos1, pos2) in positions.iter().tuple_combinations() {
+            let delta = Vec2::try_from(*pos1)? - Vec2::try_from(*pos2)?;
+
+            let found = if harmonics {
+                // every step in line with the pair counts, out to the edge either way
+                grid.ray(*pos1, delta)
+                    .chain(grid.ray(*pos2, -delta))
+                    .collect::<Vec<Pos>>()
+            } else {
+                grid.offset(*pos1, delta)
+                    .into_iter()
+                    .chain(grid.offset(*pos2, -delta))
+                    .collect()
+            };
+            for pos in found {
+                antinodes.entry(pos).or_insert(*frequency);
+            }
+        }
+    }
+
+    Ok(antinodes)
+}
+
+/// antenna positions grouped by frequency
+pub type Antennas = HashMap<char, Vec<Pos>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day08.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&data).unwrap(), 14);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part2(&data).unwrap(), 34);
+    }
+
+    #[test]
+    fn render_colours_antinodes_by_frequency() {
+        let data = Day08::parse(EXAMPLE).unwrap();
+        let (answer, picture) = Day08::render(Part::One, &data).unwrap().unwrap();
+        assert_eq!(answer, 14);
+        // the two frequencies' antinodes are told apart by colour
+        let hashes = picture
+            .cells
+            .iter()
+            .filter(|(_, cell)| cell.ch == '#')
+            .map(|(_, cell)| cell.style.fg)
+            .collect::<Vec<_>>();
+        assert_eq!(hashes.iter().unique().count(), 2);
+        // one antinode lands on an antenna, so it's drawn as the antenna
+        assert_eq!(hashes.len(), 13);
+    }
+
+    #[test]
+    fn maps_need_not_be_square() {
+        // the antinodes fall at x = 1 and x = 7 on a map only 2 rows tall
+        let data = Day08::parse("........\n...a.a..\n").unwrap();
+        assert_eq!(Day08::part1(&data).unwrap(), 2);
+    }
+}
@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+
+use super::Day09;
+
+/// the disk one block at a time, `None` for free space
+fn blocks(map: &[usize]) -> Vec<Option<u64>> {
+    let mut disk = Vec::new();
+    for (i, &length) in map.iter().enumerate() {
+        let block = if i % 2 == 0 { Some(i as u64 / 2) } else { None };
+        disk.extend(vec![block; length]);
+    }
+    disk
+}
+
+fn checksum(disk: &[Option<u64>]) -> u64 {
+    disk.iter()
+        .enumerate()
+        .map(|(i, id)| i as u64 * id.unwrap_or(0))
+        .sum()
+}
+
+/// move the last file block into the first gap, one block at a time
+fn compact_blocks(map: &[usize]) -> u64 {
+    let mut disk = blocks(map);
+    while let Some(gap) = disk.iter().position(Option::is_none) {
+        let last = disk.iter().rposition(Option::is_some);
+        match last {
+            Some(last) if last > gap => disk.swap(gap, last),
+            _ => break,
+        }
+    }
+    checksum(&disk)
+}
+
+/// move whole files, highest id first, into the first gap to their left that fits,
+/// searching block by block rather than keeping track of spans
+fn compact_files(map: &[usize]) -> u64 {
+    let mut disk = blocks(map);
+    let files = map.len().div_ceil(2) as u64;
+    for id in (0..files).rev() {
+        let Some(start) = disk.iter().position(|b| *b == Some(id)) else {
+            continue;
+        };
+        let length = disk.iter().filter(|b| **b == Some(id)).count();
+        let gap = (0..start).find(|&i| disk[i..i + length].iter().all(Option::is_none));
+        if let Some(gap) = gap {
+            for i in 0..length {
+                disk.swap(gap + i, start + i);
+            }
+        }
+    }
+    checksum(&disk)
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force(map in prop::collection::vec(0usize..10, 1..30)) {
+        let input = map.iter().map(usize::to_string).collect::<String>();
+        let data = Day09::parse(&input).unwrap();
+
+        prop_assert_eq!(Day09::part1(&data).unwrap(), compact_blocks(&map));
+        prop_assert_eq!(Day09::part2(&data).unwrap(), compact_files(&map));
+    }
+}
@@ -0,0 +1,17 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// a disk map of `size` files, each followed by a gap except the last
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut out = String::new();
+    for i in 0..size.max(1) {
+        if i > 0 {
+            out.push(char::from(b'0' + rng.random_range(0..=9)));
+        }
+        out.push(char::from(b'0' + rng.random_range(1..=9)));
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,319 @@
+use std::iter;
+
+extern crate anyhow;
+
+use aoc_common::{ParseError, Solution};
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    /// the disk map's digits, alternating file and free space lengths
+    type Input = Vec<usize>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = input.trim_end();
+        if map.is_empty() {
+            return Err(ParseError::end_of_input("a disk map"));
+        }
+        map.chars()
+            .enumerate()
+            .map(|(i, ch)| {
+                ch.to_digit(10)
+                    .map(|d| d as usize)
+                    .ok_or_else(|| ParseError::at_char(i, ch, "digit"))
+            })
+            .collect()
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u64> {
+        let mut disk = to_blocks(data);
+
+        let mut free_cursor = 0usize;
+        let mut file_cursor = disk.len().saturating_sub(1);
+
+        // keep moving files until we meet in the middle
+        while free_cursor < file_cursor {
+            // move free cursor, without running past the file cursor in case there's no
+            // free space left
+            while free_cursor < file_cursor && matches!(disk[free_cursor], Block::File(_)) {
+                free_cursor += 1;
+            }
+
+            // move file cursor
+            while free_cursor < file_cursor && matches!(disk[file_cursor], Block::Free) {
+                file_cursor -= 1;
+            }
+
+            // double check exit condition, then swap
+            if free_cursor < file_cursor {
+                disk.swap(free_cursor, file_cursor);
+            }
+        }
+
+        Ok(calc_checksum(&disk))
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u64> {
+        let (mut free_space, mut files) = to_spans(data);
+
+        // for each file, check for free space
+        for file in files.iter_mut().rev() {
+            move_file(file, &mut free_space);
+        }
+
+        Ok(calc_span_checksum(&files))
+    }
+}
+
+/// move a file into the leftmost gap before it that it fits in, if there is one
+fn move_file(file: &mut FileSpace, free_space: &mut [FreeSpace]) -> bool {
+    for space in free_space.iter_mut() {
+        if space.pos > file.pos {
+            return false;
+        }
+
+        // if the file fits, move it
+        if space.length >= file.length {
+            file.pos = space.pos;
+            space.length -= file.length;
+            space.pos += file.length;
+            return true;
+        }
+    }
+    false
+}
+
+/// a step of compacting whole files the way part two does, holding only what changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Compaction {
+    /// how many files have had their turn to move
+    pub step: usize,
+    /// the file looked at last, if any have been yet
+    pub last: Option<FileMove>,
+    /// the checksum of the disk as it is after this step
+    pub checksum: u64,
+}
+
+/// a file's turn to be moved, and where it went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMove {
+    pub id: usize,
+    pub from: usize,
+    /// where the file ended up, or `None` if there was no gap to put it in
+    pub to: Option<usize>,
+}
+
+/// compact the disk the way part two does, one file at a time from the end, starting
+/// with the disk as the map lays it out
+pub fn compaction(lengths: &[usize]) -> impl Iterator<Item = Compaction> {
+    let (mut free_space, mut files) = to_spans(lengths);
+    let mut to_move = (0..files.len()).rev();
+    let mut next = Some(Compaction {
+        step: 0,
+        last: None,
+        checksum: calc_span_checksum(&files),
+    });
+
+    iter::from_fn(move || {
+        let current = next.take()?;
+        next = to_move.next().map(|i| {
+            let file = &mut files[i];
+            let (from, before) = (file.pos, file_checksum(file));
+            let moved = move_file(file, &mut free_space);
+            Compaction {
+                step: current.step + 1,
+                last: Some(FileMove {
+                    id: file.id,
+                    from,
+                    to: moved.then_some(file.pos),
+                }),
+                checksum: current.checksum - before + file_checksum(file),
+            }
+        });
+        Some(current)
+    })
+}
+
+/// which file is in each block of the disk once `moves` have been made, or `None` for
+/// free space
+pub fn layout(lengths: &[usize], moves: impl IntoIterator<Item = FileMove>) -> Vec<Option<usize>> {
+    let (_, mut files) = to_spans(lengths);
+    for FileMove { id, to, .. } in moves {
+        if let Some(to) = to {
+            files[id].pos = to;
+        }
+    }
+    let mut blocks = vec![None; lengths.iter().sum()];
+    for file in &files {
+        blocks[file.pos..file.pos + file.length].fill(Some(file.id));
+    }
+    blocks
+}
+
+/// lay the disk map out one block at a time
+fn to_blocks(lengths: &[usize]) -> Vec<Block> {
+    lengths
+        .chunks(2)
+        .enumerate()
+        .flat_map(|(i, lengths)| {
+            let mut blocks = vec![Block::File(i as u32); lengths[0]];
+            if lengths.len() > 1 {
+                blocks.extend(vec![Block::Free; lengths[1]]);
+            }
+            blocks
+        })
+        .collect::<Vec<Block>>()
+}
+
+/// lay the disk map out as whole files and gaps
+fn to_spans(lengths: &[usize]) -> (Vec<FreeSpace>, Vec<FileSpace>) {
+    let mut free_space: Vec<FreeSpace> = Vec::new();
+    let mut files = Vec::new();
+
+    let mut disk_index = 0;
+    lengths.chunks(2).enumerate().for_each(|(id, lengths)| {
+        let file = lengths[0];
+        files.push(FileSpace {
+            id,
+            pos: disk_index,
+            length: file,
+        });
+        disk_index += file;
+        if lengths.len() > 1 {
+            let free = lengths[1];
+            // an empty file leaves the gaps either side of it as one gap
+            match free_space.last_mut() {
+                Some(last) if last.pos + last.length == disk_index => last.length += free,
+                _ => free_space.push(FreeSpace {
+                    pos: disk_index,
+                    length: free,
+                }),
+            }
+            disk_index += free;
+        }
+    });
+
+    (free_space, files)
+}
+
+fn calc_checksum(disk: &[Block]) -> u64 {
+    disk.iter()
+        .map(|block| match block {
+            Block::File(n) => *n,
+            Block::Free => 0,
+        })
+        .enumerate()
+        .map(|(i, n)| i as u64 * (n as u64))
+        .sum()
+}
+
+fn calc_span_checksum(files: &[FileSpace]) -> u64 {
+    files.iter().map(file_checksum).sum()
+}
+
+fn file_checksum(file: &FileSpace) -> u64 {
+    let (id, pos, len) = (file.id as u64, file.pos as u64, file.length as u64);
+    // derived from gauss' formula
+    id * (len.pow(2) + 2 * pos * len - len) / 2
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Block {
+    Free,
+    File(u32),
+}
+#[derive(Debug)]
+struct FreeSpace {
+    pos: usize,
+    length: usize,
+}
+#[derive(Debug, Clone)]
+struct FileSpace {
+    id: usize,
+    pos: usize,
+    length: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day09.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part1(&data).unwrap(), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part2(&data).unwrap(), 2858);
+    }
+
+    #[test]
+    fn compaction_moves_one_file_at_a_time() {
+        let data = Day09::parse(EXAMPLE).unwrap();
+        let steps = compaction(&data).collect::<Vec<Compaction>>();
+        // the disk as laid out, then a step for each of the 10 files
+        assert_eq!(steps.len(), 11);
+        assert_eq!(steps[0].last, None);
+        let drawn = |steps: &[Compaction]| {
+            layout(&data, steps.iter().filter_map(|step| step.last))
+                .iter()
+                .map(|block| block.map_or('.', |id| char::from_digit(id as u32, 10).unwrap()))
+                .collect::<String>()
+        };
+        assert_eq!(
+            drawn(&steps[..1]),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+        assert_eq!(
+            steps[1].last,
+            Some(FileMove {
+                id: 9,
+                from: 40,
+                to: Some(2)
+            })
+        );
+        // 8 has nowhere to go
+        assert_eq!(steps[2].last.unwrap().to, None);
+        assert_eq!(drawn(&steps), "00992111777.44.333....5555.6666.....8888..");
+        assert!(steps.iter().enumerate().all(|(i, step)| step.step == i));
+        assert_eq!(steps.last().unwrap().checksum, 2858);
+    }
+
+    #[test]
+    fn checksum_of_compacted_blocks() {
+        // 0099811188827773336446555566 from the puzzle statement, with free space after
+        let mut disk = "0099811188827773336446555566"
+            .chars()
+            .map(|c| Block::File(c.to_digit(10).unwrap()))
+            .collect::<Vec<Block>>();
+        disk.extend(vec![Block::Free; 14]);
+        assert_eq!(calc_checksum(&disk), 1928);
+    }
+
+    #[test]
+    fn checksum_skips_free_blocks() {
+        let disk = [Block::File(0), Block::Free, Block::File(2), Block::File(3)];
+        assert_eq!(calc_checksum(&disk), 2 * 2 + 3 * 3);
+    }
+
+    #[test]
+    fn disks_without_free_space_or_files() {
+        for (map, checksum) in [("5", 0), ("0", 0), ("30", 0), ("1010", 1), ("09", 0)] {
+            let data = Day09::parse(map).unwrap();
+            assert_eq!(Day09::part1(&data).unwrap(), checksum, "{}", map);
+            assert_eq!(Day09::part2(&data).unwrap(), checksum, "{}", map);
+        }
+    }
+}
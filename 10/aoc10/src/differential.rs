@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+
+use aoc_common::Solution;
+
+use super::{generate, Day10};
+
+const STEPS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+fn uphill(map: &[Vec<u32>], (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+    STEPS
+        .iter()
+        .map(|(dx, dy)| (x as i32 + dx, y as i32 + dy))
+        .filter(|&(nx, ny)| {
+            ny >= 0 && nx >= 0 && (ny as usize) < map.len() && (nx as usize) < map[0].len()
+        })
+        .map(|(nx, ny)| (nx as usize, ny as usize))
+        .filter(|&(nx, ny)| map[ny][nx] == map[y][x] + 1)
+        .collect()
+}
+
+fn cells(map: &[Vec<u32>], height: u32) -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
+    for (y, row) in map.iter().enumerate() {
+        for (x, h) in row.iter().enumerate() {
+            if *h == height {
+                cells.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// flood uphill from each trailhead and count the nines it reaches
+fn scores(map: &[Vec<u32>]) -> u32 {
+    let mut total = 0;
+    for trailhead in cells(map, 0) {
+        let mut reached = HashSet::from([trailhead]);
+        let mut frontier = vec![trailhead];
+        while let Some(cell) = frontier.pop() {
+            for next in uphill(map, cell) {
+                if reached.insert(next) {
+                    frontier.push(next);
+                }
+            }
+        }
+        total += reached.iter().filter(|(x, y)| map[*y][*x] == 9).count() as u32;
+    }
+    total
+}
+
+/// count trails a height at a time: the ways to reach a cell are the ways to reach every
+/// cell one lower next to it
+fn ratings(map: &[Vec<u32>]) -> u32 {
+    let mut ways = vec![vec![0; map[0].len()]; map.len()];
+    for (x, y) in cells(map, 0) {
+        ways[y][x] = 1;
+    }
+    for height in 0..9 {
+        for cell in cells(map, height) {
+            for (nx, ny) in uphill(map, cell) {
+                ways[ny][nx] += ways[cell.1][cell.0];
+            }
+        }
+    }
+    cells(map, 9).iter().map(|(x, y)| ways[*y][*x]).sum()
+}
+
+proptest! {
+    // random heights almost never line up into trails, so lean on the generator, which
+    // carves some in
+    #[test]
+    fn matches_brute_force(seed in any::<u64>(), size in 1usize..12) {
+        let input = generate::generate(seed, size);
+        let map = input
+            .lines()
+            .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+            .collect::<Vec<Vec<u32>>>();
+        let data = Day10::parse(&input).unwrap();
+
+        prop_assert_eq!(Day10::part1(&data).unwrap(), scores(&map));
+        prop_assert_eq!(Day10::part2(&data).unwrap(), ratings(&map));
+    }
+}
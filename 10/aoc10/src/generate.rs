@@ -0,0 +1,37 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// a `size` by `size` map of random heights with some hiking trails worn into it
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let mut heights = vec![vec![0u8; size]; size];
+    for row in heights.iter_mut() {
+        for height in row.iter_mut() {
+            *height = rng.random_range(0..=9);
+        }
+    }
+
+    // walk uphill one step at a time from random spots, so there are trails to find
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.random_range(0..size), rng.random_range(0..size));
+        for height in 0..=9 {
+            heights[y][x] = height;
+            match rng.random_range(0..4) {
+                0 if x + 1 < size => x += 1,
+                1 if x > 0 => x -= 1,
+                2 if y + 1 < size => y += 1,
+                3 if y > 0 => y -= 1,
+                _ => (),
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for row in heights {
+        out.extend(row.into_iter().map(|h| char::from(b'0' + h)));
+        out.push('\n');
+    }
+    out
+}
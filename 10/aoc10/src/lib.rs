@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+extern crate anyhow;
+
+use aoc_common::{Cell, Colour, Grid, ParseError, Part, Picture, Pos, Solution, Style};
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = TopographicMap;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let heights = Grid::parse(input, |ch| ch.to_digit(10), "height digit")?;
+
+        Ok(TopographicMap { heights })
+    }
+
+    fn part1(data: &Self::Input) -> anyhow::Result<u32> {
+        Ok(data
+            .get_trailheads()
+            .map(|trailhead| data.get_nines(trailhead).len() as u32)
+            .sum())
+    }
+
+    fn part2(data: &Self::Input) -> anyhow::Result<u32> {
+        Ok(data
+            .get_trailheads()
+            .map(|trailhead| data.count_nines(trailhead))
+            .sum())
+    }
+
+    fn render(part: Part, data: &Self::Input) -> anyhow::Result<Option<(u32, Picture)>> {
+        // each trailhead's nines, which part 1 counts separately for every trailhead
+        let reached = data
+            .get_trailheads()
+            .map(|trailhead| data.get_nines(trailhead))
+            .collect::<Vec<_>>();
+        let nines = reached.iter().flatten().copied().collect::<HashSet<Pos>>();
+        let (answer, trails) = match part {
+            Part::One => (reached.iter().map(|n| n.len() as u32).sum(), HashSet::new()),
+            Part::Two => (Self::part2(data)?, data.get_trail_cells()),
+        };
+
+        // shade the map from dark valleys to light peaks, with what was found on top
+        let shade = |height: u32| {
+            Colour::ramp(
+                Colour::Rgb(20, 40, 20),
+                Colour::Rgb(170, 210, 150),
+                height,
+                9,
+            )
+        };
+        let trailhead = Style::fg(Colour::Green).bold();
+        let nine = Style::fg(Colour::Red).bold();
+        let trail = Style::fg(Colour::Yellow);
+        let picture = Picture::draw(&data.heights, |pos, height| {
+            let style = match height {
+                0 => trailhead,
+                9 if nines.contains(&pos) => nine,
+                _ if trails.contains(&pos) => trail,
+                _ => Style::PLAIN,
+            };
+            let ch = char::from_digit(*height, 10).unwrap_or('?');
+            Cell::new(ch, style.on(shade(*height)))
+        });
+
+        let picture = picture
+            .with_key(Cell::new('0', trailhead.on(shade(0))), "a trailhead")
+            .with_key(
+                Cell::new('9', nine.on(shade(9))),
+                "a nine some trailhead reaches",
+            );
+        let picture = match part {
+            Part::One => picture,
+            Part::Two => picture.with_key(Cell::new('5', trail.on(shade(5))), "on a hiking trail"),
+        };
+        Ok(Some((answer, picture)))
+    }
+}
+
+#[derive(Debug)]
+pub struct TopographicMap {
+    heights: Grid<u32>,
+}
+impl TopographicMap {
+    pub fn heights(&self) -> &Grid<u32> {
+        &self.heights
+    }
+    /// neighbours exactly one step higher than `pos`
+    fn get_uphill(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let height = self.heights[pos];
+        self.heights
+            .neighbours4(pos)
+            .filter(move |neighbor| self.heights[*neighbor] == height + 1)
+    }
+    fn get_trailheads(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.heights
+            .iter()
+            .filter(|(_pos, h)| **h == 0)
+            .map(|(pos, _h)| pos)
+    }
+    fn get_nines(&self, trail: Pos) -> HashSet<Pos> {
+        // base case
+        if self.heights[trail] == 9 {
+            return HashSet::from([trail]);
+        }
+        // otherwise, sum of the neighbors
+        self.get_uphill(trail)
+            .flat_map(|neighbor| self.get_nines(neighbor))
+            .collect()
+    }
+    /// every position on some trail from a trailhead up to a nine
+    fn get_trail_cells(&self) -> HashSet<Pos> {
+        let mut reaches_nine = HashMap::new();
+        let mut on_trail = HashSet::new();
+        let mut to_visit = self.get_trailheads().collect::<Vec<Pos>>();
+        while let Some(pos) = to_visit.pop() {
+            if self.reaches_nine(pos, &mut reaches_nine) && on_trail.insert(pos) {
+                to_visit.extend(self.get_uphill(pos));
+            }
+        }
+        on_trail
+    }
+    /// whether going uphill from `pos` can get to a nine, remembering the answer
+    fn reaches_nine(&self, pos: Pos, known: &mut HashMap<Pos, bool>) -> bool {
+        if let Some(reaches) = known.get(&pos) {
+            return *reaches;
+        }
+        let uphill = self.get_uphill(pos).collect::<Vec<Pos>>();
+        let reaches = self.heights[pos] == 9
+            || uphill
+                .into_iter()
+                .any(|neighbor| self.reaches_nine(neighbor, known));
+        known.insert(pos, reaches);
+        reaches
+    }
+    fn count_nines(&self, trail: Pos) -> u32 {
+        // base case
+        if self.heights[trail] == 9 {
+            return 1;
+        }
+        // otherwise, sum of the neighbors
+        self.get_uphill(trail)
+            .map(|neighbor| self.count_nines(neighbor))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day10.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&data).unwrap(), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&data).unwrap(), 81);
+    }
+
+    #[test]
+    fn trails_only_cover_cells_leading_to_a_nine() {
+        // the 2 under the 1 is a dead end
+        let data = Day10::parse("0123456789\n5255555555\n").unwrap();
+        let trails = data.get_trail_cells();
+        assert_eq!(trails.len(), 10);
+        assert!(!trails.contains(&Pos::new(1, 1)));
+
+        let (answer, picture) = Day10::render(Part::Two, &data).unwrap().unwrap();
+        assert_eq!(answer, 1);
+        assert_eq!(picture.cells[Pos::new(9, 0)].style.fg, Some(Colour::Red));
+        assert_eq!(picture.cells[Pos::new(1, 1)].style.fg, None);
+        // higher ground is shaded lighter
+        let shade = |pos| picture.cells[pos].style.bg.map(Colour::rgb);
+        assert!(shade(Pos::new(9, 0)) > shade(Pos::new(1, 1)));
+    }
+}
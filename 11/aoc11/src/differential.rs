@@ -0,0 +1,32 @@
+use proptest::prelude::*;
+
+use super::Stones;
+
+/// actually blink, keeping every stone
+fn simulate(mut stones: Vec<u64>, blinks: u32) -> usize {
+    for _ in 0..blinks {
+        stones = stones
+            .into_iter()
+            .flat_map(|stone| {
+                let digits = stone.to_string();
+                if stone == 0 {
+                    vec![1]
+                } else if digits.len() % 2 == 0 {
+                    let (left, right) = digits.split_at(digits.len() / 2);
+                    vec![left.parse().unwrap(), right.parse().unwrap()]
+                } else {
+                    vec![stone * 2024]
+                }
+            })
+            .collect();
+    }
+    stones.len()
+}
+
+proptest! {
+    #[test]
+    fn matches_brute_force(stones in prop::collection::vec(0u64..100_000, 1..5), blinks in 0u32..20) {
+        let expected = simulate(stones.clone(), blinks) as u64;
+        prop_assert_eq!(Stones { stones }.count_after_blinks(blinks).unwrap(), expected);
+    }
+}
@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// a line of `size` stones with numbers of up to six digits
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let stones = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.random_range(1..=6);
+            rng.random_range(0..10u64.pow(digits)).to_string()
+        })
+        .collect::<Vec<String>>();
+    stones.join(" ") + "\n"
+}
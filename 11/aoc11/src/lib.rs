@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, HashMap};
+use std::iter;
+
+extern crate anyhow;
+use anyhow::anyhow;
+
+use aoc_common::{parse_num, ParseError, Solution};
+
+pub mod generate;
+
+#[cfg(test)]
+mod differential;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Stones;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut stones = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            for stone in line.split_whitespace() {
+                stones.push(parse_num(i, line, stone)?);
+            }
+        }
+
+        Ok(Stones { stones })
+    }
+
+    fn part1(stones: &Self::Input) -> anyhow::Result<u64> {
+        stones.count_after_blinks(25)
+    }
+
+    fn part2(stones: &Self::Input) -> anyhow::Result<u64> {
+        stones.count_after_blinks(75)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stones {
+    stones: Vec<u64>,
+}
+impl Stones {
+    pub fn stones(&self) -> &[u64] {
+        &self.stones
+    }
+    fn count_after_blinks(&self, count: u32) -> anyhow::Result<u64> {
+        let mut cache: HashMap<(u64, u32), u64> = HashMap::new();
+
+        self.stones
+            .iter()
+            .try_fold(0u64, |total, stone| {
+                total.checked_add(rec_blink(*stone, count, &mut cache)?)
+            })
+            .ok_or_else(|| anyhow!("a stone or the number of stones got too big for a u64"))
+    }
+    /// blink over and over, starting from the stones as they are. it stops once a stone,
+    /// or how many there are, gets too big for a u64
+    pub fn blinking(&self) -> impl Iterator<Item = Blink> {
+        let mut stones = BTreeMap::new();
+        for stone in &self.stones {
+            *stones.entry(*stone).or_insert(0) += 1;
+        }
+        iter::successors(Some(Blink { blinks: 0, stones }), Blink::next)
+    }
+}
+
+/// the stones after some number of blinks, as how many there are with each number, since
+/// the order they're in never matters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blink {
+    pub blinks: u32,
+    pub stones: BTreeMap<u64, u64>,
+}
+impl Blink {
+    pub fn count(&self) -> u64 {
+        self.stones.values().sum()
+    }
+    /// the stones after one more blink, or `None` if they can't be counted any more
+    fn next(&self) -> Option<Blink> {
+        let mut stones = BTreeMap::new();
+        for (stone, count) in &self.stones {
+            let (left, right) = blink_stone(*stone)?;
+            for new in iter::once(left).chain(right) {
+                let total: &mut u64 = stones.entry(new).or_insert(0);
+                *total = total.checked_add(*count)?;
+            }
+        }
+        // make sure `count` can't overflow either
+        stones
+            .values()
+            .try_fold(0u64, |total, count| total.checked_add(*count))?;
+        Some(Blink {
+            blinks: self.blinks + 1,
+            stones,
+        })
+    }
+}
+
+/// how many stones a single stone turns into after blinking `steps` times, or `None` if
+/// a stone or the count overflows
+fn rec_blink(stone: u64, steps: u32, cache: &mut HashMap<(u64, u32), u64>) -> Option<u64> {
+    // check memo
+    if let Some(val) = cache.get(&(stone, steps)) {
+        return Some(*val);
+    }
+
+    // otherwise, figure out the answer recursively
+    let ans =
+        if steps == 0 {
+            // base case
+            1
+        } else {
+            match blink_stone(stone)? {
+                (left, Some(right)) => rec_blink(left, steps - 1, cache)?
+                    .checked_add(rec_blink(right, steps - 1, cache)?)?,
+                (stone, None) => rec_blink(stone, steps - 1, cache)?,
+            }
+        };
+
+    // update memo
+    cache.insert((stone, steps), ans);
+
+    Some(ans)
+}
+
+/// what a stone turns into when blinked at: one stone, or two if it splits. `None` if
+/// the new number doesn't fit in a u64
+fn blink_stone(stone: u64) -> Option<(u64, Option<u64>)> {
+    if stone == 0 {
+        return Some((1, None));
+    }
+    let num_digits = stone.ilog10() + 1;
+    if num_digits.is_multiple_of(2) {
+        let left = stone / 10u64.pow(num_digits / 2);
+        let right = stone - (left * 10u64.pow(num_digits / 2));
+        Some((left, Some(right)))
+    } else {
+        Some((stone.checked_mul(2024)?, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/day11.example.txt");
+
+    #[test]
+    fn part1_example() {
+        let data = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&data).unwrap(), 55312);
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&data).unwrap(), 65601038650482);
+    }
+
+    #[test]
+    fn rec_blink_follows_the_rules() {
+        let mut cache = HashMap::new();
+        // 0 becomes 1
+        assert_eq!(rec_blink(0, 1, &mut cache), Some(1));
+        // an even number of digits splits in two
+        assert_eq!(rec_blink(1000, 1, &mut cache), Some(2));
+        // anything else is multiplied by 2024
+        assert_eq!(rec_blink(1, 1, &mut cache), Some(1));
+        // 125 -> 253000 -> 253 0 -> 512072 1 -> 512 72 2024
+        assert_eq!(rec_blink(125, 4, &mut cache), Some(3));
+        assert_eq!(rec_blink(17, 6, &mut cache), Some(15));
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let data = Day11::parse("9999999999999999999\n").unwrap();
+        assert!(Day11::part1(&data).is_err());
+        // blinking stops rather than overflowing, after the stones as they started
+        assert_eq!(data.blinking().count(), 1);
+    }
+
+    #[test]
+    fn blinking_counts_the_same_stones() {
+        let data = Day11::parse(EXAMPLE).unwrap();
+        let mut blinking = data.blinking();
+        let start = blinking.next().unwrap();
+        assert_eq!((start.blinks, start.count()), (0, 2));
+        let after = blinking.nth(24).unwrap();
+        assert_eq!((after.blinks, after.count()), (25, 55312));
+        assert_eq!(data.blinking().nth(75).unwrap().count(), 65601038650482);
+    }
+}
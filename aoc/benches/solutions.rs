@@ -0,0 +1,61 @@
+//! parse and solve timings for every day
+//!
+//! each day is measured against `inputs/dayNN.txt` when it exists, falling back to the
+//! committed `inputs/dayNN.example.txt`. criterion keeps the last run under
+//! `target/criterion` and reports the change against it automatically; to compare against
+//! a fixed point instead, save one with `cargo bench -p aoc -- --save-baseline before`
+//! and check a change with `cargo bench -p aoc -- --baseline before`.
+
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc_common::{Part, Solution};
+
+fn fixture(day: u32) -> String {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+    let real = dir.join(format!("day{:02}.txt", day));
+    let path: PathBuf = if real.exists() {
+        real
+    } else {
+        dir.join(format!("day{:02}.example.txt", day))
+    };
+
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let input = fixture(S::DAY);
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+
+    group.bench_function("parse", |b| {
+        b.iter(|| S::parse(black_box(&input)).expect("fixture parses"))
+    });
+
+    let data = S::parse(&input).expect("fixture parses");
+    for part in Part::BOTH {
+        group.bench_function(format!("part{}", part), |b| {
+            b.iter(|| S::solve(part, black_box(&data)).expect("fixture solves"))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_day::<aoc01::Day01>,
+    bench_day::<aoc02::Day02>,
+    bench_day::<aoc03::Day03>,
+    bench_day::<aoc04::Day04>,
+    bench_day::<aoc05::Day05>,
+    bench_day::<aoc06::Day06>,
+    bench_day::<aoc07::Day07>,
+    bench_day::<aoc08::Day08>,
+    bench_day::<aoc09::Day09>,
+    bench_day::<aoc10::Day10>,
+    bench_day::<aoc11::Day11>,
+);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use sha2::{Digest, Sha256};
+
+use aoc_common::Part;
+
+/// hex sha256 of an input, so answers stay tied to the exact input they came from
+pub fn input_hash(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+/// known good answers, keyed by day, part and input hash
+///
+/// stored one per line as `day part sha256 answer`, with `#` starting a comment
+pub struct AnswerBook {
+    path: PathBuf,
+    answers: BTreeMap<(u32, Part, String), String>,
+}
+
+impl AnswerBook {
+    /// load the answers file, treating a missing file as an empty book
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.splitn(4, ' ').collect::<Vec<&str>>();
+            let [day, part, hash, answer] = fields[..] else {
+                bail!(
+                    "{} line {}: expected `day part sha256 answer`",
+                    path.display(),
+                    i + 1
+                );
+            };
+            let day = day
+                .parse::<u32>()
+                .with_context(|| format!("{} line {}: bad day", path.display(), i + 1))?;
+            let part = part
+                .parse::<u32>()
+                .map_err(anyhow::Error::from)
+                .and_then(Part::try_from)
+                .with_context(|| format!("{} line {}: bad part", path.display(), i + 1))?;
+            answers.insert((day, part, hash.to_string()), answer.to_string());
+        }
+
+        Ok(AnswerBook {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    pub fn get(&self, day: u32, part: Part, hash: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, hash.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, hash: &str, answer: &str) {
+        self.answers
+            .insert((day, part, hash.to_string()), answer.to_string());
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut out = String::from("# day part input-sha256 answer\n");
+        for ((day, part, hash), answer) in self.answers.iter() {
+            out.push_str(&format!("{:02} {} {} {}\n", day, part, hash, answer));
+        }
+
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        fs::write(&self.path, out)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
@@ -0,0 +1,135 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+
+use aoc_common::Part;
+
+/// the real puzzle site
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// env var that points the client somewhere else, like a local mock server
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// env var holding the session cookie, checked before the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// which event these solutions are for
+pub const YEAR: u32 = 2024;
+
+const USER_AGENT: &str = "github.com/dreary-dugong/aoc2024 aoc runner";
+
+/// talks to the puzzle site as a logged in user
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// set up a client, using the base url given, then `AOC_BASE_URL`, then the real site
+    pub fn new(base_url: Option<&str>) -> anyhow::Result<Self> {
+        let base_url = match base_url {
+            Some(url) => url.to_string(),
+            None => env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+
+        Ok(Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: load_session()?,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        })
+    }
+
+    fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    /// download a day's puzzle input
+    pub fn input(&self, day: u32) -> anyhow::Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| status_error(day, &url, e))?;
+        response
+            .into_string()
+            .with_context(|| format!("failed to read the response from {}", url))
+    }
+
+    /// send an answer for one part of a day, giving back the page the site answers with
+    pub fn submit(&self, day: u32, part: Part, answer: &str) -> anyhow::Result<String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| status_error(day, &url, e))?;
+        response
+            .into_string()
+            .with_context(|| format!("failed to read the response from {}", url))
+    }
+}
+
+/// turn a failed request into an error that says what to do about it
+fn status_error(day: u32, url: &str, error: ureq::Error) -> anyhow::Error {
+    let (code, response) = match error {
+        ureq::Error::Status(code, response) => (code, response),
+        ureq::Error::Transport(e) => {
+            return anyhow::Error::new(e).context(format!("failed to reach {}", url))
+        }
+    };
+
+    match code {
+        404 => anyhow::anyhow!("day {} isn't unlocked yet (404 from {})", day, url),
+        429 => match response.header("Retry-After") {
+            Some(wait) => anyhow::anyhow!("rate limited by {}, try again in {} seconds", url, wait),
+            None => anyhow::anyhow!("rate limited by {}, wait a while and try again", url),
+        },
+        400 | 401 | 403 => anyhow::anyhow!(
+            "{} rejected the session token ({}), it may have expired",
+            url,
+            code
+        ),
+        _ => {
+            let body = response.into_string().unwrap_or_default();
+            let first_line = body.lines().next().unwrap_or_default();
+            anyhow::anyhow!("{} answered {}: {}", url, code, first_line)
+        }
+    }
+}
+
+/// the session cookie, from `AOC_SESSION` or the `session` file in the config directory
+pub fn load_session() -> anyhow::Result<String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let Some(path) = config_dir().map(|d| d.join("session")) else {
+        bail!("no session token: set {}", SESSION_VAR);
+    };
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => bail!("session file {} is empty", path.display()),
+        Err(e) if e.kind() == ErrorKind::NotFound => bail!(
+            "no session token: set {} or put it in {}",
+            SESSION_VAR,
+            path.display()
+        ),
+        Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// `$XDG_CONFIG_HOME/aoc`, falling back to `~/.config/aoc`
+fn config_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("aoc"))
+}
@@ -0,0 +1,351 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use aoc_common::{Cell, Colour, Input, Picture, Pos, Style};
+
+use crate::simulations::{Breakpoint, FileCompaction, GuardPatrol, Simulation, StoneBlinking};
+use crate::timeline::Timeline;
+
+/// what the keys do, for the side panel
+const KEYS: &[(&str, &str)] = &[
+    ("→ l", "step forward"),
+    ("← h", "step back"),
+    ("space", "play or pause"),
+    ("c", "run to a breakpoint"),
+    ("g", "back to the start"),
+    ("+ -", "play faster or slower"),
+    ("q", "quit"),
+];
+
+/// how many steps to take between checking for key presses while running to a breakpoint,
+/// so a long run can still be stopped
+const RUN_BATCH: usize = 1000;
+
+/// step through a day's simulation in the terminal
+pub fn debug(
+    day: u32,
+    input: &Input,
+    breakpoints: &[Breakpoint],
+    delay: Duration,
+) -> anyhow::Result<()> {
+    match day {
+        6 => run(&GuardPatrol::new(input)?, breakpoints, delay),
+        9 => run(&FileCompaction::new(input)?, breakpoints, delay),
+        11 => run(&StoneBlinking::new(input)?, breakpoints, delay),
+        _ => bail!(
+            "day {} has no simulation to step through, only days 6, 9 and 11 do",
+            day
+        ),
+    }
+}
+
+fn run<S: Simulation>(
+    simulation: &S,
+    breakpoints: &[Breakpoint],
+    delay: Duration,
+) -> anyhow::Result<()> {
+    for breakpoint in breakpoints {
+        if !matches!(breakpoint, Breakpoint::Step(_)) && !simulation.understands(breakpoint) {
+            bail!(
+                "{} can't stop at `{}`, only at {} or step:N",
+                S::NAME,
+                breakpoint,
+                S::BREAKPOINTS
+            );
+        }
+    }
+    let timeline = Timeline::new(|| simulation.run()).context("the simulation has no steps")?;
+
+    let mut debugger = Debugger {
+        simulation,
+        timeline,
+        breakpoints,
+        playing: false,
+        running: false,
+        delay,
+        message: String::new(),
+    };
+    // put the terminal back the way it was even if drawing fails part way
+    let mut terminal = ratatui::init();
+    let result = debugger.event_loop(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+struct Debugger<'a, S: Simulation> {
+    simulation: &'a S,
+    timeline: Timeline<'a, S::Snapshot>,
+    breakpoints: &'a [Breakpoint],
+    playing: bool,
+    /// whether it's running to the next breakpoint as fast as it can
+    running: bool,
+    /// how long to show each step for while playing
+    delay: Duration,
+    /// why playing stopped, or anything else worth saying
+    message: String,
+}
+
+impl<S: Simulation> Debugger<'_, S> {
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            // while playing, a key press only cuts the wait for the next step short, and
+            // while running one is only looked for between batches of steps
+            let wait = if self.running {
+                Duration::ZERO
+            } else if self.playing {
+                self.delay
+            } else {
+                Duration::from_secs(3600)
+            };
+            if !event::poll(wait)? {
+                if self.running {
+                    self.running = self.advance_by(RUN_BATCH);
+                } else if self.playing {
+                    self.playing = self.advance();
+                }
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            self.message.clear();
+            // any key stops a run, even if it's only to change the speed
+            self.running = false;
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.playing = false;
+                    self.advance();
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.playing = false;
+                    if !self.timeline.back() {
+                        self.message = String::from("already at the start");
+                    }
+                }
+                KeyCode::Char(' ') => self.playing = !self.playing,
+                KeyCode::Char('c') => {
+                    self.playing = false;
+                    self.running = true;
+                }
+                KeyCode::Char('g') | KeyCode::Home => {
+                    self.playing = false;
+                    self.timeline.go_to(0);
+                }
+                KeyCode::Char('+') => self.delay = (self.delay / 2).max(Duration::from_millis(1)),
+                KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(5)),
+                _ => (),
+            }
+        }
+    }
+
+    /// take a step, saying so and returning false if that's the end or a breakpoint
+    fn advance(&mut self) -> bool {
+        if !self.timeline.forward() {
+            self.message = String::from("that's the end of the simulation");
+            return false;
+        }
+        let index = self.timeline.index();
+        let snapshot = self.timeline.current();
+        let hit = self.breakpoints.iter().find(|breakpoint| match breakpoint {
+            Breakpoint::Step(n) => index == *n,
+            _ => self.simulation.hits(breakpoint, snapshot),
+        });
+        match hit {
+            Some(breakpoint) => {
+                self.message = format!("stopped at breakpoint `{}`", breakpoint);
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// take up to `steps` steps, returning false if it stopped short like `advance` does
+    fn advance_by(&mut self, steps: usize) -> bool {
+        (0..steps).all(|_| self.advance())
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(5)]).areas(frame.area());
+        let [view, side] =
+            Layout::horizontal([Constraint::Min(10), Constraint::Length(30)]).areas(main);
+
+        let snapshot = self.timeline.current();
+        let picture = self.simulation.draw(snapshot);
+        let block = Block::bordered().title(S::NAME);
+        let inner = block.inner(view);
+        let focus = self.simulation.focus(snapshot);
+        frame.render_widget(
+            Paragraph::new(visible(&picture, focus, inner)).block(block),
+            view,
+        );
+
+        let mut side_text = vec![Line::styled(
+            "breakpoints",
+            ratatui::style::Style::new().add_modifier(Modifier::BOLD),
+        )];
+        if self.breakpoints.is_empty() {
+            side_text.push(Line::raw("  none"));
+        }
+        for breakpoint in self.breakpoints {
+            side_text.push(Line::raw(format!("  {}", breakpoint)));
+        }
+        side_text.push(Line::raw(""));
+        for (keys, action) in KEYS {
+            side_text.push(Line::raw(format!("{:>7}  {}", keys, action)));
+        }
+        frame.render_widget(Paragraph::new(side_text).block(Block::bordered()), side);
+
+        let state = if self.running {
+            "running to a breakpoint"
+        } else if self.playing {
+            "playing"
+        } else {
+            "paused"
+        };
+        let status_text = vec![
+            Line::raw(format!(
+                "step {}, {} ({}ms a step)",
+                self.timeline.index(),
+                state,
+                self.delay.as_millis()
+            )),
+            Line::raw(self.simulation.describe(snapshot)),
+            Line::styled(
+                self.message.as_str(),
+                ratatui::style::Style::new().fg(Color::Yellow),
+            ),
+        ];
+        frame.render_widget(
+            Paragraph::new(status_text)
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered()),
+            status,
+        );
+    }
+}
+
+/// the part of a picture that fits in `area`, centred on `focus` where it can be
+fn visible(picture: &Picture, focus: Option<Pos>, area: Rect) -> Text<'static> {
+    let (width, height) = (usize::from(area.width), usize::from(area.height));
+    let focus = focus.unwrap_or_default();
+    // keep the focus in the middle, without scrolling past the picture's far edges
+    let left = focus
+        .x
+        .saturating_sub(width / 2)
+        .min(picture.cells.width().saturating_sub(width));
+    let top = focus
+        .y
+        .saturating_sub(height / 2)
+        .min(picture.cells.height().saturating_sub(height));
+
+    let lines = picture
+        .cells
+        .rows()
+        .skip(top)
+        .take(height)
+        .map(|row| {
+            let cells = row.iter().skip(left).take(width);
+            Line::from(cells.map(|cell| to_span(*cell)).collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+fn to_span(cell: Cell) -> Span<'static> {
+    Span::styled(cell.ch.to_string(), to_style(cell.style))
+}
+
+fn to_style(style: Style) -> ratatui::style::Style {
+    let mut out = ratatui::style::Style::new();
+    if let Some(colour) = style.fg {
+        out = out.fg(to_color(colour));
+    }
+    if let Some(colour) = style.bg {
+        out = out.bg(to_color(colour));
+    }
+    if style.bold {
+        out = out.add_modifier(Modifier::BOLD);
+    }
+    out
+}
+
+fn to_color(colour: Colour) -> Color {
+    match colour {
+        Colour::Black => Color::Black,
+        Colour::Red => Color::Red,
+        Colour::Green => Color::Green,
+        Colour::Yellow => Color::Yellow,
+        Colour::Blue => Color::Blue,
+        Colour::Magenta => Color::Magenta,
+        Colour::Cyan => Color::Cyan,
+        Colour::White => Color::White,
+        Colour::Grey => Color::DarkGray,
+        Colour::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Grid;
+
+    #[test]
+    fn big_pictures_scroll_to_the_focus() {
+        let grid = Grid::new(10, 10, (0..100).collect());
+        let picture = Picture::draw(&grid, |_, n| {
+            Cell::plain(char::from_digit(n % 10, 10).unwrap())
+        });
+        let text = |focus| {
+            visible(&picture, focus, Rect::new(0, 0, 4, 2))
+                .lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(text(None), ["0123", "0123"]);
+        assert_eq!(text(Some(Pos::new(5, 5))), ["3456", "3456"]);
+        // never past the bottom right corner
+        assert_eq!(text(Some(Pos::new(9, 9))), ["6789", "6789"]);
+    }
+
+    #[test]
+    fn runs_go_in_batches_until_something_stops_them() {
+        let disk = FileCompaction::new(&Input {
+            text: String::from("2333133121414131402"),
+            source: String::from("example"),
+        })
+        .unwrap();
+        let breakpoints = [Breakpoint::FileMoves(4)];
+        let mut debugger = Debugger {
+            simulation: &disk,
+            timeline: Timeline::new(|| disk.run()).unwrap(),
+            breakpoints: &breakpoints,
+            playing: false,
+            running: true,
+            delay: Duration::ZERO,
+            message: String::new(),
+        };
+        assert!(debugger.advance_by(2));
+        assert_eq!(debugger.timeline.index(), 2);
+        // files take their turns from 9 down, and 4 moves on the sixth
+        assert!(!debugger.advance_by(RUN_BATCH));
+        assert_eq!(debugger.timeline.index(), 6);
+        assert_eq!(debugger.message, "stopped at breakpoint `move:4`");
+        assert!(!debugger.advance_by(RUN_BATCH));
+        assert_eq!(debugger.message, "that's the end of the simulation");
+    }
+}
@@ -0,0 +1,228 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+use aoc_common::{Cell, Colour, Picture};
+
+/// how wide and tall a cell is in an image, in pixels for a PNG
+const CELL: usize = 8;
+/// what shows through wherever a cell has no background of its own
+const BACKGROUND: Colour = Colour::Rgb(24, 24, 24);
+/// text with no colour of its own, as it would be on a dark terminal
+const FOREGROUND: Colour = Colour::White;
+
+/// the kinds of image a picture can be saved as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// the format going by a file's extension
+    pub fn for_path(path: &Path) -> anyhow::Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("png") => Ok(ImageFormat::Png),
+            Some("svg") => Ok(ImageFormat::Svg),
+            _ => bail!(
+                "can only export to .png or .svg files, not {}",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// save a picture to `path` as whichever kind of image its extension asks for
+pub fn export(picture: &Picture, path: &Path) -> anyhow::Result<()> {
+    let bytes = match ImageFormat::for_path(path)? {
+        ImageFormat::Png => to_png(picture)?,
+        ImageFormat::Svg => to_svg(picture).into_bytes(),
+    };
+    fs::write(path, bytes).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// the picture as a PNG. there's no font to draw characters with, so each cell is a
+/// square of its background with a smaller square of its text colour in the middle
+/// marking anything but empty space, bigger for bold cells. the key is left off
+pub fn to_png(picture: &Picture) -> anyhow::Result<Vec<u8>> {
+    let width = picture.cells.width() * CELL;
+    let height = picture.cells.height() * CELL;
+    let mut pixels = vec![0; width * height * 3];
+
+    for (pos, cell) in picture.cells.iter() {
+        let background = cell.style.bg.unwrap_or(BACKGROUND).rgb();
+        let mark = cell.style.fg.filter(|_| !matches!(cell.ch, '.' | ' '));
+        let inset = if cell.style.bold { CELL / 8 } else { CELL / 4 };
+        let in_mark = |d: usize| (inset..CELL - inset).contains(&d);
+
+        for dy in 0..CELL {
+            for dx in 0..CELL {
+                let (r, g, b) = match mark {
+                    Some(colour) if in_mark(dx) && in_mark(dy) => colour.rgb(),
+                    _ => background,
+                };
+                let i = ((pos.y * CELL + dy) * width + pos.x * CELL + dx) * 3;
+                pixels[i..i + 3].copy_from_slice(&[r, g, b]);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, u32::try_from(width)?, u32::try_from(height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+    Ok(out)
+}
+
+/// the picture as an SVG, drawn the way a terminal would show it, with the key underneath
+pub fn to_svg(picture: &Picture) -> String {
+    let rows = picture.cells.height() + picture.key.len();
+    // the key's explanations can run wider than the grid
+    let columns = picture
+        .key
+        .iter()
+        .map(|(_, meaning)| meaning.chars().count() + 2)
+        .chain([picture.cells.width()])
+        .max()
+        .unwrap_or(0);
+    let (width, height) = (columns * CELL, rows * CELL);
+
+    let mut svg = format!(
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" ",
+            "viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"{size}\" ",
+            "dominant-baseline=\"central\">\n",
+            "<rect width=\"{w}\" height=\"{h}\" fill=\"{fill}\"/>\n"
+        ),
+        w = width,
+        h = height,
+        size = CELL,
+        fill = hex(BACKGROUND)
+    );
+    for (pos, cell) in picture.cells.iter() {
+        svg.push_str(&svg_cell(pos.x, pos.y, *cell));
+    }
+    for (row, (cell, meaning)) in picture.key.iter().enumerate() {
+        let y = picture.cells.height() + row;
+        svg.push_str(&svg_cell(0, y, *cell));
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>\n",
+            2 * CELL,
+            y * CELL + CELL / 2,
+            hex(FOREGROUND),
+            escape(meaning)
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// a cell's background, if it has one, and its character centred on top
+fn svg_cell(x: usize, y: usize, cell: Cell) -> String {
+    let mut out = String::new();
+    if let Some(colour) = cell.style.bg {
+        out.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+            x * CELL,
+            y * CELL,
+            CELL,
+            CELL,
+            hex(colour)
+        ));
+    }
+    if cell.ch != ' ' {
+        let weight = if cell.style.bold {
+            " font-weight=\"bold\""
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" fill=\"{}\"{}>{}</text>\n",
+            x * CELL + CELL / 2,
+            y * CELL + CELL / 2,
+            hex(cell.style.fg.unwrap_or(FOREGROUND)),
+            weight,
+            escape(&cell.ch.to_string())
+        ));
+    }
+    out
+}
+
+fn hex(colour: Colour) -> String {
+    let (r, g, b) = colour.rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// text made safe to put between XML tags
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Grid, Style};
+
+    fn picture() -> Picture {
+        let grid = Grid::parse(".#\n<.\n", Some, "a cell").unwrap();
+        Picture::draw(&grid, |_, ch| match ch {
+            '#' => Cell::new('#', Style::fg(Colour::Red).bold()),
+            '<' => Cell::new('<', Style::fg(Colour::Cyan).on(Colour::Blue)),
+            _ => Cell::new('.', Style::fg(Colour::Grey)),
+        })
+        .with_key(Cell::new('#', Style::fg(Colour::Red)), "a wall & a <tag>")
+    }
+
+    #[test]
+    fn formats_go_by_extension() {
+        let format = |path: &str| ImageFormat::for_path(Path::new(path)).ok();
+        assert_eq!(format("out.png"), Some(ImageFormat::Png));
+        assert_eq!(format("dir/OUT.SVG"), Some(ImageFormat::Svg));
+        assert_eq!(format("out.jpg"), None);
+        assert_eq!(format("out"), None);
+    }
+
+    #[test]
+    fn png_cells_are_squares_with_marks_in_the_middle() {
+        let bytes = to_png(&picture()).unwrap();
+        let decoder = png::Decoder::new(std::io::Cursor::new(bytes));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (16, 16));
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 16 + x) * 3;
+            (pixels[i], pixels[i + 1], pixels[i + 2])
+        };
+        // empty space is all background, even with a text colour
+        assert_eq!(pixel(4, 4), BACKGROUND.rgb());
+        // the bold wall has a mark reaching all but one pixel from its edge
+        assert_eq!(pixel(8, 0), BACKGROUND.rgb());
+        assert_eq!(pixel(9, 1), Colour::Red.rgb());
+        // the guard's mark sits on its own background
+        assert_eq!(pixel(0, 8), Colour::Blue.rgb());
+        assert_eq!(pixel(4, 12), Colour::Cyan.rgb());
+    }
+
+    #[test]
+    fn svg_draws_every_cell_and_the_key() {
+        let svg = to_svg(&picture());
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<text").count(), 4 + 2);
+        assert!(svg.contains("fill=\"#2472c8\""));
+        assert!(svg.contains(">&lt;</text>"));
+        assert!(svg.contains(">a wall &amp; a &lt;tag&gt;</text>"));
+    }
+}
@@ -0,0 +1,35 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+
+use crate::client::Client;
+use crate::inputs;
+
+/// download a day's input into the input directory, unless it's already there
+pub fn fetch(day: u32, dirs: &[PathBuf], base_url: Option<&str>) -> anyhow::Result<()> {
+    // inputs never change once they're out, so a copy anywhere is good enough
+    if let Ok(path) = inputs::find_input(dirs, day, false) {
+        println!("day {:02} is already cached at {}", day, path.display());
+        return Ok(());
+    }
+
+    let client = Client::new(base_url)?;
+    let text = client.input(day)?;
+    if text.trim().is_empty() {
+        bail!("got an empty input for day {}", day);
+    }
+
+    let dir = inputs::home_dir(dirs);
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let path = dir.join(inputs::file_name(day, false));
+
+    // write to the side first, so an interrupted fetch can't leave half an input cached
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, text).with_context(|| format!("failed to write {}", partial.display()))?;
+    fs::rename(&partial, &path)
+        .with_context(|| format!("failed to move {} into place", partial.display()))?;
+
+    println!("saved day {:02} input to {}", day, path.display());
+    Ok(())
+}
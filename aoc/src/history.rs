@@ -0,0 +1,190 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+use aoc_common::Part;
+
+/// what the site said about a guess
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// submitted too soon after the last guess, so it wasn't judged
+    Wait,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::TooHigh => "high",
+            Verdict::TooLow => "low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Verdict::Right,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::Wait,
+        ]
+        .into_iter()
+        .find(|v| v.name() == name)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let text = match self {
+            Verdict::Right => "right",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "not judged, submitted too soon",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Guess {
+    pub day: u32,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// every answer ever submitted, oldest first
+///
+/// stored one per line as `day part verdict answer`, with `#` starting a comment
+pub struct GuessHistory {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessHistory {
+    /// load the history file, treating a missing file as no guesses yet
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+
+        let mut guesses = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.splitn(4, ' ').collect::<Vec<&str>>();
+            let [day, part, verdict, answer] = fields[..] else {
+                bail!(
+                    "{} line {}: expected `day part verdict answer`",
+                    path.display(),
+                    i + 1
+                );
+            };
+            let day = day
+                .parse::<u32>()
+                .with_context(|| format!("{} line {}: bad day", path.display(), i + 1))?;
+            let part = part
+                .parse::<u32>()
+                .map_err(anyhow::Error::from)
+                .and_then(Part::try_from)
+                .with_context(|| format!("{} line {}: bad part", path.display(), i + 1))?;
+            let Some(verdict) = Verdict::from_name(verdict) else {
+                bail!("{} line {}: bad verdict", path.display(), i + 1);
+            };
+            guesses.push(Guess {
+                day,
+                part,
+                verdict,
+                answer: answer.to_string(),
+            });
+        }
+
+        Ok(GuessHistory {
+            path: path.to_path_buf(),
+            guesses,
+        })
+    }
+
+    /// why submitting this answer would be a waste of a guess, if it would be
+    pub fn objection(&self, day: u32, part: Part, answer: &str) -> Option<String> {
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|g| g.day == day && g.part == part)
+            .collect::<Vec<&Guess>>();
+
+        if let Some(right) = guesses.iter().find(|g| g.verdict == Verdict::Right) {
+            return Some(format!("already solved, the answer was {}", right.answer));
+        }
+        if let Some(same) = guesses
+            .iter()
+            .find(|g| g.answer == answer && g.verdict != Verdict::Wait)
+        {
+            return Some(format!(
+                "{} was already guessed and was {}",
+                answer, same.verdict
+            ));
+        }
+
+        // only numbers can be out of bounds
+        let value = answer.parse::<i128>().ok()?;
+        for guess in guesses {
+            let Ok(bound) = guess.answer.parse::<i128>() else {
+                continue;
+            };
+            match guess.verdict {
+                Verdict::TooHigh if value >= bound => {
+                    return Some(format!("{} is too high, {} already was", answer, bound))
+                }
+                Verdict::TooLow if value <= bound => {
+                    return Some(format!("{} is too low, {} already was", answer, bound))
+                }
+                _ => (),
+            }
+        }
+        None
+    }
+
+    /// add a guess to the history, writing it straight to disk
+    pub fn record(&mut self, guess: Guess) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+        if self.guesses.is_empty() && file.metadata()?.len() == 0 {
+            writeln!(file, "# day part verdict answer")?;
+        }
+        writeln!(
+            file,
+            "{:02} {} {} {}",
+            guess.day,
+            guess.part,
+            guess.verdict.name(),
+            guess.answer
+        )
+        .with_context(|| format!("failed to write {}", self.path.display()))?;
+
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
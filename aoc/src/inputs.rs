@@ -0,0 +1,166 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+/// env var that points at a directory of inputs, used when no directory is given
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// known answers, kept next to the inputs by default
+pub const ANSWERS_FILE: &str = "answers.txt";
+/// guesses sent to the site, kept next to the inputs by default
+pub const GUESSES_FILE: &str = "guesses.txt";
+
+/// where to look for inputs, most preferred first
+///
+/// an explicit directory is the only place looked at; otherwise `AOC_INPUT_DIR` if set,
+/// then `inputs/` in the workspace around the current directory, then `inputs/` in the
+/// workspace this binary was built from
+pub fn input_dirs(explicit: Option<&Path>) -> Vec<PathBuf> {
+    if let Some(dir) = explicit {
+        return vec![dir.to_path_buf()];
+    }
+
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR).filter(|d| !d.is_empty()) {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(root) = env::current_dir().ok().and_then(|d| workspace_root(&d)) {
+        dirs.push(root.join("inputs"));
+    }
+    let built_from = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("inputs");
+    if let Ok(dir) = built_from.canonicalize() {
+        // usually the same workspace as the one above
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+
+    dirs
+}
+
+/// the first of the input directories that actually exists, for files that get written
+/// next to the inputs
+pub fn home_dir(dirs: &[PathBuf]) -> PathBuf {
+    dirs.iter()
+        .find(|d| d.is_dir())
+        .or(dirs.first())
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+/// the nearest directory at or above `start` whose Cargo.toml declares a workspace
+pub fn workspace_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|l| l.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// the file name a day's input goes by
+pub fn file_name(day: u32, example: bool) -> String {
+    if example {
+        format!("day{:02}.example.txt", day)
+    } else {
+        format!("day{:02}.txt", day)
+    }
+}
+
+/// find a day's input in the first directory that has it
+pub fn find_input(dirs: &[PathBuf], day: u32, example: bool) -> anyhow::Result<PathBuf> {
+    let tried = dirs
+        .iter()
+        .map(|dir| dir.join(file_name(day, example)))
+        .collect::<Vec<PathBuf>>();
+    if let Some(path) = tried.iter().find(|p| p.is_file()) {
+        return Ok(path.clone());
+    }
+
+    if tried.is_empty() {
+        bail!(
+            "no input for day {}: not in a workspace and {} is not set",
+            day,
+            INPUT_DIR_VAR
+        );
+    }
+    let tried = tried
+        .iter()
+        .map(|p| format!("  {}", p.display()))
+        .collect::<Vec<String>>()
+        .join("\n");
+    bail!("no input for day {}, tried:\n{}", day, tried)
+}
+
+/// the files to run a day against, with any directories swapped for the inputs inside
+/// them that could be for that day
+pub fn expand_inputs(paths: &[PathBuf], day: u32) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+
+        let mut inside = fs::read_dir(path)
+            .with_context(|| format!("failed to read {}", path.display()))?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<PathBuf>, _>>()
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        inside.retain(|p| p.is_file() && could_be_input(p, day));
+        if inside.is_empty() {
+            bail!(
+                "{} has no input files for day {} in it",
+                path.display(),
+                day
+            );
+        }
+        inside.sort();
+        files.extend(inside);
+    }
+    Ok(files)
+}
+
+/// whether a file in a directory of inputs could be one for `day`: a .txt file that
+/// isn't hidden, isn't the answers or guesses, and is either named for the day, like
+/// day05.txt or day05.example.txt, or not named for any day, like alice.txt
+fn could_be_input(path: &Path, day: u32) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let Some(stem) = name.strip_suffix(".txt") else {
+        return false;
+    };
+    if name.starts_with('.') || name == ANSWERS_FILE || name == GUESSES_FILE {
+        return false;
+    }
+    let named_for = stem
+        .strip_prefix("day")
+        .and_then(|rest| rest.get(..2))
+        .and_then(|nn| nn.parse::<u32>().ok());
+    named_for.is_none_or(|d| d == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_files_for_the_day_count_as_inputs() {
+        let could_be = |name: &str| could_be_input(Path::new(name), 5);
+        assert!(could_be("day05.txt"));
+        assert!(could_be("inputs/day05.example.txt"));
+        assert!(could_be("alice.txt"));
+        assert!(!could_be("day06.txt"));
+        assert!(!could_be("answers.txt"));
+        assert!(!could_be("guesses.txt"));
+        assert!(!could_be(".day05.txt"));
+        assert!(!could_be("day05.txt.orig"));
+        assert!(!could_be("notes.md"));
+    }
+}
@@ -0,0 +1,41 @@
+//! every day's solution, for calling from other programs
+//!
+//! `solve` runs a day by number on puzzle input held in memory and hands back the answer
+//! as text, without printing anything. for a typed answer, go through a day's crate and
+//! the `Solution` trait instead, e.g. `aoc::aoc07::Day07::answer(Part::Two, input)`
+
+extern crate anyhow;
+use anyhow::bail;
+
+pub use aoc_common::{
+    Cell, Colour, Direction4, Direction8, Grid, ParseError, Part, Picture, Pos, Solution, Style,
+    Vec2,
+};
+
+pub use aoc01;
+pub use aoc02;
+pub use aoc03;
+pub use aoc04;
+pub use aoc05;
+pub use aoc06;
+pub use aoc07;
+pub use aoc08;
+pub use aoc09;
+pub use aoc10;
+pub use aoc11;
+
+pub mod export;
+pub mod registry;
+
+/// solve one part of a day, given the puzzle input as text
+pub fn solve(day: u32, part: Part, input: &str) -> anyhow::Result<String> {
+    let Some(entry) = registry::SOLUTIONS.iter().find(|e| e.day == day) else {
+        bail!("no solution registered for day {}", day);
+    };
+    (entry.answer)(part, input)
+}
+
+/// every day there's a solution for
+pub fn days() -> impl Iterator<Item = u32> {
+    registry::SOLUTIONS.iter().map(|e| e.day)
+}
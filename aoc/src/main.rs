@@ -0,0 +1,562 @@
+mod answers;
+mod client;
+mod debug;
+mod fetch;
+mod history;
+mod inputs;
+mod report;
+mod scaffold;
+mod simulations;
+mod submit;
+mod timeline;
+mod verify;
+mod watch;
+
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+extern crate clap;
+use clap::{ArgAction, Parser, Subcommand};
+
+extern crate anyhow;
+use anyhow::{bail, Context};
+
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+
+use aoc::export::{self, ImageFormat};
+use aoc::registry::{Drawing, Entry, SOLUTIONS};
+use aoc_common::Part;
+
+use answers::AnswerBook;
+use report::{Format, Layout, Record, Report};
+use simulations::Breakpoint;
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// log what the solutions are doing to stderr, -v for decisions and -vv for
+    /// everything. RUST_LOG filters further, e.g. RUST_LOG=aoc05=trace
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// run one or more solutions
+    Run(RunArgs),
+    /// check solutions against previously recorded answers
+    Verify(VerifyArgs),
+    /// download a day's puzzle input into the input directory
+    Fetch(FetchArgs),
+    /// send an answer to the site and keep track of how it went
+    Submit(SubmitArgs),
+    /// rebuild and re-run a day whenever its source or input changes
+    Watch(WatchArgs),
+    /// generate the crate for a new day and register it with the workspace, the
+    /// dispatcher and the fuzz targets. adding it to the benches is left until it's solved
+    New(NewArgs),
+    /// print a random input for a day
+    Gen(GenArgs),
+    /// step through a day's simulation in the terminal, forwards and backwards
+    Debug(DebugArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+    /// day to run
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u32>,
+
+    /// part to run, runs both parts if left out
+    #[arg(short, long, requires = "day", value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// run every registered solution
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// input files, or directories of them, to run against; `-` for stdin, looked up in
+    /// the input directory if left out. a directory gives up its .txt files named for
+    /// the day, like day05.example.txt, or for no day at all, like alice.txt
+    #[arg(short, long, num_args = 1..)]
+    input: Vec<PathBuf>,
+
+    #[command(flatten)]
+    lookup: LookupArgs,
+
+    /// print how long parsing and solving took to stderr
+    #[arg(long)]
+    time: bool,
+
+    /// how to print results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// draw the puzzle state behind each answer as a coloured grid, for days on a map
+    #[arg(long, conflicts_with_all = ["all", "format", "time"])]
+    render: bool,
+
+    /// save the puzzle state behind the answer as a .png or .svg image, for days on a
+    /// map. with both parts, each gets its own file, e.g. out-part1.png
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["all", "format", "time"])]
+    export: Option<PathBuf>,
+}
+
+/// where the inputs live, for every command that reads or saves them
+#[derive(clap::Args, Debug)]
+struct InputDirArgs {
+    /// directory holding the dayNN.txt inputs, instead of $AOC_INPUT_DIR or the
+    /// workspace's inputs/
+    #[arg(long)]
+    input_dir: Option<PathBuf>,
+}
+
+impl InputDirArgs {
+    /// the directories to look in, in order
+    fn dirs(&self) -> Vec<PathBuf> {
+        inputs::input_dirs(self.input_dir.as_deref())
+    }
+}
+
+/// which of a day's inputs to use when no file is given, for commands that take one
+#[derive(clap::Args, Debug)]
+struct LookupArgs {
+    /// use dayNN.example.txt from the input directory instead of dayNN.txt
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+
+    #[command(flatten)]
+    input_dir: InputDirArgs,
+}
+
+impl LookupArgs {
+    /// the input file for a day
+    fn find(&self, day: u32) -> anyhow::Result<PathBuf> {
+        inputs::find_input(&self.input_dir.dirs(), day, self.example)
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct VerifyArgs {
+    /// only check this day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    #[command(flatten)]
+    input_dir: InputDirArgs,
+
+    /// answers file, defaults to answers.txt in the input directory
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// store answers for inputs that don't have one yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct FetchArgs {
+    /// day to download
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    #[command(flatten)]
+    input_dir: InputDirArgs,
+
+    /// site to download from, instead of $AOC_BASE_URL or adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct SubmitArgs {
+    /// day to submit for
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// part to submit for
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+
+    /// answer to send, worked out from the day's input if left out
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    input_dir: InputDirArgs,
+
+    /// guess history file, defaults to guesses.txt in the input directory
+    #[arg(long)]
+    history: Option<PathBuf>,
+
+    /// site to submit to, instead of $AOC_BASE_URL or adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct WatchArgs {
+    /// day to watch
+    #[arg(short, long)]
+    day: u32,
+
+    /// part to run, runs both parts if left out
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// path to the input file, looked up in the input directory if left out
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    lookup: LookupArgs,
+
+    /// build with optimisations, for timings worth comparing
+    #[arg(long)]
+    release: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct NewArgs {
+    /// day to create
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+}
+
+#[derive(clap::Args, Debug)]
+struct GenArgs {
+    /// day to generate an input for
+    #[arg(short, long)]
+    day: u32,
+
+    /// seed for the random generator, picked at random and printed to stderr if left out
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// roughly how big an input to make, in lines, cells or items depending on the day
+    #[arg(short = 'k', long, default_value_t = 20)]
+    size: usize,
+}
+
+#[derive(clap::Args, Debug)]
+struct DebugArgs {
+    /// day to step through: 6, 9 or 11
+    #[arg(short, long)]
+    day: u32,
+
+    /// path to the input file, looked up in the input directory if left out
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    lookup: LookupArgs,
+
+    /// where to stop while playing: `turn` for day 6, `move:ID` for day 9,
+    /// `stones:COUNT` for day 11 or `step:N` for any of them
+    #[arg(short, long = "break", value_name = "BREAKPOINT")]
+    breakpoints: Vec<Breakpoint>,
+
+    /// milliseconds to show each step for while playing
+    #[arg(long, default_value_t = 200)]
+    delay: u64,
+}
+
+/// what gets printed in place of a plain message when a json format is asked for
+#[derive(serde::Serialize)]
+struct ErrorRecord {
+    error: String,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    let format = match &cli.command {
+        Command::Run(args) => args.format,
+        _ => Format::Text,
+    };
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => {
+            let dirs = args.input_dir.dirs();
+            let answers = args
+                .answers
+                .unwrap_or_else(|| inputs::home_dir(&dirs).join(inputs::ANSWERS_FILE));
+            verify::verify(
+                &mut io::stdout().lock(),
+                args.day,
+                &dirs,
+                &answers,
+                args.record,
+            )
+        }
+        Command::Fetch(args) => {
+            fetch::fetch(args.day, &args.input_dir.dirs(), args.base_url.as_deref())
+        }
+        Command::Submit(args) => submit(args),
+        Command::New(args) => std::env::current_dir()
+            .ok()
+            .and_then(|d| inputs::workspace_root(&d))
+            .context("aoc new has to be run from inside the workspace")
+            .and_then(|root| scaffold::new_day(&root, args.day)),
+        Command::Gen(args) => generate(args),
+        Command::Debug(args) => {
+            let path = match args.input {
+                Some(path) => Ok(path),
+                None => args.lookup.find(args.day),
+            };
+            path.and_then(|path| aoc_common::read_input(Some(&path)))
+                .and_then(|input| {
+                    debug::debug(
+                        args.day,
+                        &input,
+                        &args.breakpoints,
+                        Duration::from_millis(args.delay),
+                    )
+                })
+        }
+        Command::Watch(args) => {
+            let dirs = args.lookup.input_dir.dirs();
+            args.part.map(Part::try_from).transpose().and_then(|part| {
+                watch::watch(
+                    args.day,
+                    part,
+                    args.input,
+                    args.lookup.example,
+                    &dirs,
+                    args.release,
+                )
+            })
+        }
+    };
+
+    if let Err(e) = result {
+        // whatever was reading the output has all it wanted, which isn't a failure
+        if is_broken_pipe(&e) {
+            process::exit(0);
+        }
+        if format == Format::Text {
+            eprintln!("{:#}", e);
+        } else {
+            eprintln!(
+                "{}",
+                report::to_json(&ErrorRecord {
+                    error: format!("{:#}", e)
+                })
+            );
+        }
+        process::exit(1);
+    } else {
+        process::exit(0);
+    }
+}
+
+fn is_broken_pipe(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .filter_map(|cause| cause.downcast_ref::<io::Error>())
+        .any(|e| e.kind() == io::ErrorKind::BrokenPipe)
+}
+
+fn generate(args: GenArgs) -> anyhow::Result<()> {
+    let Some(entry) = SOLUTIONS.iter().find(|e| e.day == args.day) else {
+        bail!("no solution registered for day {}", args.day);
+    };
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            let seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos() as u64);
+            eprintln!("seed: {}", seed);
+            seed
+        }
+    };
+    print!("{}", (entry.generate)(seed, args.size));
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> anyhow::Result<()> {
+    let dirs = args.input_dir.dirs();
+    let history = args
+        .history
+        .unwrap_or_else(|| inputs::home_dir(&dirs).join(inputs::GUESSES_FILE));
+    submit::submit(
+        args.day,
+        Part::try_from(args.part)?,
+        args.answer,
+        &dirs,
+        &history,
+        args.base_url.as_deref(),
+    )
+}
+
+/// send log events to stderr at the level -v asks for, unless RUST_LOG says otherwise
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none())
+        .without_time()
+        .init();
+}
+
+fn run(args: RunArgs) -> anyhow::Result<()> {
+    let dirs = args.lookup.input_dir.dirs();
+    let example = args.lookup.example;
+    if args.all {
+        let mut report = Report::new(args.format, Layout::Labelled, args.time);
+        run_all(&dirs, example, &mut report)?;
+        return report.finish();
+    }
+
+    let day = args.day.expect("clap requires a day without --all");
+    let Some(entry) = SOLUTIONS.iter().find(|e| e.day == day) else {
+        bail!("no solution registered for day {}", day);
+    };
+
+    // a lone part prints just its answer
+    let parts = match args.part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::BOTH.to_vec(),
+    };
+
+    if args.render || args.export.is_some() {
+        if args.input.len() > 1 || args.input.iter().any(|p| p.is_dir()) {
+            bail!("--render and --export draw one input at a time");
+        }
+        // catch a bad file name before spending any time solving
+        if let Some(export) = &args.export {
+            ImageFormat::for_path(export)?;
+        }
+        let path = match args.input.first() {
+            Some(path) => path.clone(),
+            None => args.lookup.find(day)?,
+        };
+        return draw(
+            &mut io::stdout().lock(),
+            entry,
+            &parts,
+            &path,
+            args.render,
+            args.export.as_deref(),
+        );
+    }
+
+    if args.input.len() > 1 || args.input.iter().any(|p| p.is_dir()) {
+        let answers = AnswerBook::load(&inputs::home_dir(&dirs).join(inputs::ANSWERS_FILE))?;
+        let mut report = Report::new(args.format, Layout::Batch, args.time).with_answers(answers);
+        for path in inputs::expand_inputs(&args.input, day)? {
+            run_day(entry, &parts, &path, &mut report)?;
+        }
+        return report.finish();
+    }
+
+    let layout = if parts.len() > 1 {
+        Layout::Labelled
+    } else {
+        Layout::Bare
+    };
+    let mut report = Report::new(args.format, layout, args.time);
+    match args.input.first() {
+        Some(path) => run_day(entry, &parts, path, &mut report)?,
+        None => match args.lookup.find(day) {
+            Ok(path) => run_day(entry, &parts, &path, &mut report)?,
+            Err(e) => {
+                let path = PathBuf::from(inputs::file_name(day, example));
+                report.add(Record::unreadable(day, &path, e))?;
+            }
+        },
+    }
+    report.finish()
+}
+
+/// read an input and run the given parts of a day against it, once each
+fn run_day(entry: &Entry, parts: &[Part], path: &Path, report: &mut Report) -> io::Result<()> {
+    // read the input up front so both parts can share it, even from stdin
+    match aoc_common::read_input(Some(path)) {
+        Ok(input) => {
+            for &part in parts {
+                report.add(Record::new(
+                    entry.day,
+                    part,
+                    &input,
+                    (entry.run)(part, &input),
+                ))?;
+            }
+            Ok(())
+        }
+        Err(e) => report.add(Record::unreadable(entry.day, path, e)),
+    }
+}
+
+/// print each part's answer, and show a picture of how it was found under it or save one
+/// to an image
+fn draw(
+    out: &mut impl Write,
+    entry: &Entry,
+    parts: &[Part],
+    path: &Path,
+    show: bool,
+    export: Option<&Path>,
+) -> anyhow::Result<()> {
+    let input = aoc_common::read_input(Some(path))?;
+    // escapes only make sense on a terminal, and not even there if asked not to
+    let colour = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    for &part in parts {
+        let Some(Drawing { outcome, picture }) = (entry.render)(part, &input)? else {
+            bail!("day {} has nothing to draw", entry.day);
+        };
+        writeln!(out, "part {}: {}", part, outcome.answer)?;
+        if show && colour {
+            write!(out, "{}", picture.to_ansi())?;
+        } else if show {
+            write!(out, "{}", picture)?;
+        }
+        if let Some(export) = export {
+            let file = export_path(export, part, parts.len());
+            export::export(&picture, &file)?;
+            eprintln!("saved part {} to {}", part, file.display());
+        }
+    }
+    Ok(())
+}
+
+/// where to save a part's picture: the path as given when there's just the one part,
+/// otherwise with the part added to the file name so they don't overwrite each other
+fn export_path(path: &Path, part: Part, parts: usize) -> PathBuf {
+    if parts == 1 {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name = format!("{}-part{}", stem, part);
+    if let Some(extension) = path.extension() {
+        name = format!("{}.{}", name, extension.to_string_lossy());
+    }
+    path.with_file_name(name)
+}
+
+fn run_all(dirs: &[PathBuf], example: bool, report: &mut Report) -> io::Result<()> {
+    for entry in SOLUTIONS {
+        match inputs::find_input(dirs, entry.day, example) {
+            Ok(path) => run_day(entry, &Part::BOTH, &path, report)?,
+            Err(e) => {
+                let path = PathBuf::from(inputs::file_name(entry.day, example));
+                report.add(Record::unreadable(entry.day, &path, e))?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{Input, ParseError, Part, Picture, Solution};
+use tracing::info_span;
+
+/// a day that the dispatcher knows how to run
+pub struct Entry {
+    pub day: u32,
+    pub run: fn(Part, &Input) -> anyhow::Result<Outcome>,
+    /// solve a part of raw puzzle input, without timing anything
+    pub answer: fn(Part, &str) -> anyhow::Result<String>,
+    /// make a random input from a seed and a size
+    pub generate: fn(u64, usize) -> String,
+    /// solve a part and draw the puzzle state behind its answer, for days that can
+    pub render: fn(Part, &Input) -> anyhow::Result<Option<Drawing>>,
+}
+
+/// an answer along with how long each phase took to get it
+pub struct Outcome {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// an answer along with a picture of how it was found
+pub struct Drawing {
+    pub outcome: Outcome,
+    pub picture: Picture,
+}
+
+const fn entry<S: Solution>(generate: fn(u64, usize) -> String) -> Entry {
+    Entry {
+        day: S::DAY,
+        run: run::<S>,
+        answer: answer::<S>,
+        generate,
+        render: render::<S>,
+    }
+}
+
+/// parse the input and solve one part, timing each phase separately
+fn run<S: Solution>(part: Part, input: &Input) -> anyhow::Result<Outcome> {
+    let start = Instant::now();
+    let data = parse::<S>(&input.text).map_err(|e| e.in_file(&input.source))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve::<S>(part, &data)?;
+    let solve = start.elapsed();
+
+    Ok(Outcome {
+        answer: answer.to_string(),
+        parse,
+        solve,
+    })
+}
+
+fn answer<S: Solution>(part: Part, input: &str) -> anyhow::Result<String> {
+    let data = parse::<S>(input)?;
+    Ok(solve::<S>(part, &data)?.to_string())
+}
+
+/// parse the input, then solve and draw one part in one go, so the solve time includes
+/// the drawing
+fn render<S: Solution>(part: Part, input: &Input) -> anyhow::Result<Option<Drawing>> {
+    let start = Instant::now();
+    let data = parse::<S>(&input.text).map_err(|e| e.in_file(&input.source))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let span = info_span!("render", day = S::DAY, part = part.number());
+    let Some((answer, picture)) = span.in_scope(|| S::render(part, &data))? else {
+        return Ok(None);
+    };
+    let outcome = Outcome {
+        answer: answer.to_string(),
+        parse,
+        solve: start.elapsed(),
+    };
+    Ok(Some(Drawing { outcome, picture }))
+}
+
+/// parse inside a span, so anything the day logs while parsing says so
+fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    info_span!("parse", day = S::DAY).in_scope(|| S::parse(input))
+}
+
+/// solve a part inside a span, so anything the day logs says which day and part it's from
+fn solve<S: Solution>(part: Part, data: &S::Input) -> anyhow::Result<S::Output> {
+    info_span!("solve", day = S::DAY, part = part.number()).in_scope(|| S::solve(part, data))
+}
+
+pub const SOLUTIONS: &[Entry] = &[
+    entry::<aoc01::Day01>(aoc01::generate::generate),
+    entry::<aoc02::Day02>(aoc02::generate::generate),
+    entry::<aoc03::Day03>(aoc03::generate::generate),
+    entry::<aoc04::Day04>(aoc04::generate::generate),
+    entry::<aoc05::Day05>(aoc05::generate::generate),
+    entry::<aoc06::Day06>(aoc06::generate::generate),
+    entry::<aoc07::Day07>(aoc07::generate::generate),
+    entry::<aoc08::Day08>(aoc08::generate::generate),
+    entry::<aoc09::Day09>(aoc09::generate::generate),
+    entry::<aoc10::Day10>(aoc10::generate::generate),
+    entry::<aoc11::Day11>(aoc11::generate::generate),
+];
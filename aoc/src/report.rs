@@ -0,0 +1,329 @@
+use std::cmp::Reverse;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+use aoc_common::{Input, Part};
+
+use crate::answers::{input_hash, AnswerBook};
+use aoc::registry::Outcome;
+
+/// how results get written to stdout
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// plain answers for people to read
+    Text,
+    /// a single json object, or an array of them when running more than one solution
+    Json,
+    /// one json object per line
+    Ndjson,
+}
+
+/// the result of running one part of one day, or of failing to get that far
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Record {
+    pub day: u32,
+    /// left out when the input couldn't be read, since no part got to run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    pub input_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_sha256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// record the outcome of running a part against an input
+    pub fn new(day: u32, part: Part, input: &Input, result: anyhow::Result<Outcome>) -> Self {
+        let mut record = Record {
+            day,
+            part: Some(part.number()),
+            answer: None,
+            input_path: input.source.clone(),
+            input_sha256: Some(input_hash(&input.text)),
+            parse_ns: None,
+            solve_ns: None,
+            error: None,
+        };
+        match result {
+            Ok(outcome) => {
+                record.parse_ns = Some(nanos(outcome.parse));
+                record.solve_ns = Some(nanos(outcome.solve));
+                record.answer = Some(outcome.answer);
+            }
+            Err(e) => record.error = Some(format!("{:#}", e)),
+        }
+        record
+    }
+
+    /// record a day whose input couldn't be read
+    pub fn unreadable(day: u32, path: &Path, error: anyhow::Error) -> Self {
+        Record {
+            day,
+            part: None,
+            answer: None,
+            input_path: path.display().to_string(),
+            input_sha256: None,
+            parse_ns: None,
+            solve_ns: None,
+            error: Some(format!("{:#}", error)),
+        }
+    }
+
+    pub fn parse_time(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns.unwrap_or_default())
+    }
+
+    pub fn solve_time(&self) -> Duration {
+        Duration::from_nanos(self.solve_ns.unwrap_or_default())
+    }
+
+    fn total_time(&self) -> Duration {
+        self.parse_time() + self.solve_time()
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// how text results are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// a single answer, printed on its own
+    Bare,
+    /// one line per solution with its day and part in front, with timings saved up for a
+    /// table at the end
+    Labelled,
+    /// the same day over a batch of inputs, printed as a table once everything has run
+    Batch,
+}
+
+/// collects records as solutions run and writes them out in the chosen format
+pub struct Report {
+    /// where results go, which is stdout outside of tests
+    out: Box<dyn Write>,
+    format: Format,
+    layout: Layout,
+    /// print timings to stderr in text mode
+    time: bool,
+    /// known answers to check batch results against
+    answers: Option<AnswerBook>,
+    records: Vec<Record>,
+}
+
+impl Report {
+    pub fn new(format: Format, layout: Layout, time: bool) -> Self {
+        Report {
+            out: Box::new(io::stdout().lock()),
+            format,
+            layout,
+            time,
+            answers: None,
+            records: Vec::new(),
+        }
+    }
+
+    /// check batch results against a book of known answers
+    pub fn with_answers(mut self, answers: AnswerBook) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    /// record a result, writing it out straight away unless the format has to wait for
+    /// the rest. fails only if stdout does, such as when it's piped into something that
+    /// stopped reading
+    pub fn add(&mut self, record: Record) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.print_text(&record)?,
+            Format::Ndjson => writeln!(self.out, "{}", to_json(&record))?,
+            Format::Json => (),
+        }
+        self.records.push(record);
+        Ok(())
+    }
+
+    fn print_text(&mut self, record: &Record) -> io::Result<()> {
+        let label = match record.part {
+            Some(part) => format!("day {:02} part {}", record.day, part),
+            None => format!("day {:02}", record.day),
+        };
+        match (self.layout, &record.answer, &record.error) {
+            (Layout::Bare, Some(answer), _) => writeln!(self.out, "{}", answer)?,
+            (Layout::Labelled, Some(answer), _) => writeln!(self.out, "{}: {}", label, answer)?,
+            (Layout::Labelled, None, Some(error)) => {
+                writeln!(self.out, "{}: error: {}", label, error)?
+            }
+            // a lone part's error is handed back from finish instead, and batches wait
+            // for the table
+            _ => (),
+        }
+        if self.time && record.answer.is_some() && self.layout == Layout::Bare {
+            eprintln!(
+                "parse {:.2?}, solve {:.2?}, total {:.2?}",
+                record.parse_time(),
+                record.solve_time(),
+                record.total_time()
+            );
+        }
+        Ok(())
+    }
+
+    /// write out anything held back until the end, failing if any solution did
+    pub fn finish(mut self) -> anyhow::Result<()> {
+        match (self.format, self.layout) {
+            (Format::Json, Layout::Bare) => {
+                if let Some(record) = self.records.first() {
+                    writeln!(self.out, "{}", to_json(record))?;
+                }
+            }
+            (Format::Json, _) => writeln!(self.out, "{}", to_json(&self.records))?,
+            (Format::Text, Layout::Labelled) if self.time => self.print_timing_table(),
+            (Format::Text, Layout::Batch) => self.print_batch_table()?,
+            _ => (),
+        }
+        self.out.flush()?;
+
+        let mut errors = self.records.iter().filter_map(|r| r.error.as_ref());
+        match errors.next() {
+            None => Ok(()),
+            Some(error) if self.layout == Layout::Bare && self.format == Format::Text => {
+                bail!("{}", error)
+            }
+            Some(_) => bail!("some solutions failed"),
+        }
+    }
+
+    fn print_batch_table(&mut self) -> io::Result<()> {
+        let width = self
+            .records
+            .iter()
+            .map(|r| r.input_path.len())
+            .max()
+            .unwrap_or_default()
+            .max("file".len());
+
+        writeln!(
+            self.out,
+            "{:<width$} {:<5} {:<20} {:>12} {:>12} {:>12} check",
+            "file",
+            "part",
+            "answer",
+            "parse",
+            "solve",
+            "total",
+            width = width
+        )?;
+        for record in &self.records {
+            let part = record.part.map_or(String::from("-"), |p| p.to_string());
+            let Some(answer) = &record.answer else {
+                writeln!(
+                    self.out,
+                    "{:<width$} {:<5} error: {}",
+                    record.input_path,
+                    part,
+                    record.error.as_deref().unwrap_or_default(),
+                    width = width
+                )?;
+                continue;
+            };
+            writeln!(
+                self.out,
+                "{:<width$} {:<5} {:<20} {:>12} {:>12} {:>12} {}",
+                record.input_path,
+                part,
+                answer,
+                format!("{:.2?}", record.parse_time()),
+                format!("{:.2?}", record.solve_time()),
+                format!("{:.2?}", record.total_time()),
+                self.check(record, answer),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+
+    /// how a batch answer compares to the known answer for its input, if there is one
+    fn check(&self, record: &Record, answer: &str) -> String {
+        let expected = self.answers.as_ref().and_then(|book| {
+            let part = Part::try_from(record.part?).ok()?;
+            book.get(record.day, part, record.input_sha256.as_deref()?)
+        });
+        match expected {
+            None => String::new(),
+            Some(expected) if expected == answer => String::from("ok"),
+            Some(expected) => format!("FAIL, expected {}", expected),
+        }
+    }
+
+    fn print_timing_table(&self) {
+        // slowest first, so the ones worth looking at are on top
+        let mut timed = self
+            .records
+            .iter()
+            .filter(|r| r.answer.is_some())
+            .collect::<Vec<&Record>>();
+        timed.sort_by_key(|r| Reverse(r.total_time()));
+
+        eprintln!();
+        eprintln!(
+            "{:<4} {:<5} {:>12} {:>12} {:>12}",
+            "day", "part", "parse", "solve", "total"
+        );
+        for record in timed {
+            eprintln!(
+                "{:<4} {:<5} {:>12} {:>12} {:>12}",
+                record.day,
+                record.part.unwrap_or_default(),
+                format!("{:.2?}", record.parse_time()),
+                format!("{:.2?}", record.solve_time()),
+                format!("{:.2?}", record.total_time())
+            );
+        }
+    }
+}
+
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value).expect("records always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// stdout after whatever it was piped into has exited
+    struct ClosedPipe;
+
+    impl Write for ClosedPipe {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    #[test]
+    fn closed_stdout_is_an_error_not_a_panic() {
+        for format in [Format::Text, Format::Ndjson] {
+            let mut report = Report {
+                out: Box::new(ClosedPipe),
+                ..Report::new(format, Layout::Labelled, false)
+            };
+            let record = Record::unreadable(1, Path::new("day01.txt"), anyhow::anyhow!("gone"));
+            let error = report.add(record).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "aocNN"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+anyhow.workspace = true
+rand.workspace = true
+"#;
+
+const LIB_TEMPLATE: &str = r#"extern crate anyhow;
+
+use anyhow::bail;
+
+use aoc_common::{ParseError, Solution};
+
+pub mod generate;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u32 = DAY_NUMBER;
+    type Input = Vec<String>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_data: &Self::Input) -> anyhow::Result<u64> {
+        bail!("part 1 isn't solved yet")
+    }
+
+    fn part2(_data: &Self::Input) -> anyhow::Result<u64> {
+        bail!("part 2 isn't solved yet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/dayNN.example.txt");
+
+    #[test]
+    #[ignore = "fill in the example and its answer"]
+    fn part1_example() {
+        let data = DayNN::parse(EXAMPLE).unwrap();
+        assert_eq!(DayNN::part1(&data).unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "fill in the example and its answer"]
+    fn part2_example() {
+        let data = DayNN::parse(EXAMPLE).unwrap();
+        assert_eq!(DayNN::part2(&data).unwrap(), 0);
+    }
+}
+"#;
+
+const GENERATE_TEMPLATE: &str = r#"use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` lines of random numbers, until there's something shaped like the real input
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        out.push_str(&format!("{}\n", rng.random_range(0..1000)));
+    }
+    out
+}
+"#;
+
+const FUZZ_TARGET_TEMPLATE: &str = r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| aoc_fuzz::check::<aocNN::DayNN>(data));
+"#;
+
+const FUZZ_BIN_TEMPLATE: &str = r#"[[bin]]
+name = "dayNN"
+path = "fuzz_targets/dayNN.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+/// generate a crate for a new day and hook it up to the workspace, the dispatcher and
+/// the fuzz targets. the bench is left to do by hand once the day is solved, since
+/// benching a part that errors panics and stops every other day's bench too
+pub fn new_day(root: &Path, day: u32) -> anyhow::Result<()> {
+    let nn = format!("{:02}", day);
+    let crate_dir = root.join(&nn).join(format!("aoc{}", nn));
+    if crate_dir.exists() {
+        bail!("{} already exists", crate_dir.display());
+    }
+
+    // check everything that needs editing before touching anything
+    let edits = [
+        (
+            root.join("Cargo.toml"),
+            format!("\"{}/aoc{}\",", nn, nn),
+            "\"",
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            format!("aoc{} = {{ path = \"../{}/aoc{}\" }}", nn, nn, nn),
+            "aoc",
+        ),
+        (
+            root.join("aoc/src/lib.rs"),
+            format!("pub use aoc{};", nn),
+            "pub use aoc",
+        ),
+        (
+            root.join("aoc/src/registry.rs"),
+            format!(
+                "entry::<aoc{}::Day{}>(aoc{}::generate::generate),",
+                nn, nn, nn
+            ),
+            "entry::<aoc",
+        ),
+        (
+            root.join("fuzz/Cargo.toml"),
+            format!("aoc{} = {{ path = \"../{}/aoc{}\" }}", nn, nn, nn),
+            "aoc",
+        ),
+    ];
+    let mut edited = Vec::new();
+    for (path, line, prefix) in edits {
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let Some(text) = insert_day_line(&text, day, &line, prefix) else {
+            bail!(
+                "couldn't add day {} to {}, it's either there already or there are no \
+                 other days to put it next to",
+                day,
+                path.display()
+            );
+        };
+        edited.push((path, text));
+    }
+    // the fuzz manifest needs a binary for the day's target as well as the dependency
+    for (path, text) in &mut edited {
+        if path.ends_with("fuzz/Cargo.toml") {
+            *text = insert_fuzz_bin(text, day).with_context(|| {
+                format!(
+                    "couldn't add a fuzz target for day {} to {}",
+                    day,
+                    path.display()
+                )
+            })?;
+        }
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("DAY_NUMBER", &day.to_string())
+            .replace("NN", &nn)
+    };
+    fs::create_dir_all(crate_dir.join("src"))
+        .with_context(|| format!("failed to create {}", crate_dir.display()))?;
+    let created = [
+        (crate_dir.join("Cargo.toml"), MANIFEST_TEMPLATE),
+        (crate_dir.join("src/lib.rs"), LIB_TEMPLATE),
+        (crate_dir.join("src/generate.rs"), GENERATE_TEMPLATE),
+        (
+            root.join(format!("fuzz/fuzz_targets/day{}.rs", nn)),
+            FUZZ_TARGET_TEMPLATE,
+        ),
+    ];
+    for (path, template) in created {
+        fs::write(&path, fill(template))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    let example = root.join(format!("inputs/day{}.example.txt", nn));
+    if !example.exists() {
+        let inputs = root.join("inputs");
+        fs::create_dir_all(&inputs)
+            .with_context(|| format!("failed to create {}", inputs.display()))?;
+        fs::write(&example, "")
+            .with_context(|| format!("failed to write {}", example.display()))?;
+    }
+
+    for (path, text) in edited {
+        fs::write(&path, text).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    println!("created {}", crate_dir.display());
+    println!("paste the puzzle's example into {}", example.display());
+    // benching an unsolved part panics, so this one stays a manual step
+    println!(
+        "once it's solved, add bench_day::<aoc{}::Day{}> to aoc/benches/solutions.rs",
+        nn, nn
+    );
+    Ok(())
+}
+
+/// add a line for a day to a list of lines for other days, keeping it sorted by day, or
+/// `None` if there's no such list or the day is on it already
+///
+/// the list is every line starting with `prefix` that mentions an `aocNN` crate
+fn insert_day_line(text: &str, day: u32, line: &str, prefix: &str) -> Option<String> {
+    let mut lines = text.lines().map(str::to_string).collect::<Vec<String>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.trim_start().starts_with(prefix))
+        .filter_map(|(i, l)| Some((i, day_in_line(l)?)))
+        .collect::<Vec<(usize, u32)>>();
+
+    let &(first, _) = days.first()?;
+    if days.iter().any(|(_, d)| *d == day) {
+        return None;
+    }
+    let index = days
+        .iter()
+        .rev()
+        .find(|(_, d)| *d < day)
+        .map_or(first, |(i, _)| i + 1);
+    let neighbour = &lines[if index > first { index - 1 } else { first }];
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+    lines.insert(index, format!("{}{}", indent, line));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// the day of the first `aocNN` crate a line mentions
+fn day_in_line(line: &str) -> Option<u32> {
+    let (_, rest) = line.split_once("aoc")?;
+    rest.get(..2)?.parse().ok()
+}
+
+/// add a `[[bin]]` for a day's fuzz target among the other days' ones, or `None` if
+/// there are none or the day has one already
+fn insert_fuzz_bin(text: &str, day: u32) -> Option<String> {
+    const HEADER: &str = "[[bin]]\nname = \"day";
+    let days = text
+        .match_indices(HEADER)
+        .filter_map(|(i, _)| {
+            let rest = text.get(i + HEADER.len()..)?;
+            Some((i, rest.get(..2)?.parse().ok()?))
+        })
+        .collect::<Vec<(usize, u32)>>();
+    if days.is_empty() || days.iter().any(|(_, d)| *d == day) {
+        return None;
+    }
+
+    let block = FUZZ_BIN_TEMPLATE.replace("NN", &format!("{:02}", day));
+    Some(match days.iter().find(|(_, d)| *d > day) {
+        Some(&(i, _)) => format!("{}{}\n{}", &text[..i], block, &text[i..]),
+        None => format!("{}\n\n{}", text.trim_end(), block),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMBERS: &str = "members = [\n    \"aoc\",\n    \"02/aoc02\",\n    \"04/aoc04\",\n]\n";
+
+    fn add_member(day: u32) -> Option<String> {
+        let line = format!("\"{:02}/aoc{:02}\",", day, day);
+        insert_day_line(MEMBERS, day, &line, "\"")
+    }
+
+    #[test]
+    fn days_go_in_order() {
+        assert_eq!(
+            add_member(1).unwrap(),
+            "members = [\n    \"aoc\",\n    \"01/aoc01\",\n    \"02/aoc02\",\n    \"04/aoc04\",\n]\n"
+        );
+        assert_eq!(
+            add_member(3).unwrap(),
+            "members = [\n    \"aoc\",\n    \"02/aoc02\",\n    \"03/aoc03\",\n    \"04/aoc04\",\n]\n"
+        );
+        assert_eq!(
+            add_member(12).unwrap(),
+            "members = [\n    \"aoc\",\n    \"02/aoc02\",\n    \"04/aoc04\",\n    \"12/aoc12\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn new_lines_copy_their_neighbours_indentation() {
+        let text = "criterion_group!(\n\tbenches,\n\tbench_day::<aoc05::Day05>,\n);\n";
+        let line = "bench_day::<aoc01::Day01>,";
+        assert_eq!(
+            insert_day_line(text, 1, line, "bench_day::<aoc").unwrap(),
+            "criterion_group!(\n\tbenches,\n\tbench_day::<aoc01::Day01>,\n\tbench_day::<aoc05::Day05>,\n);\n"
+        );
+    }
+
+    #[test]
+    fn nowhere_to_put_a_day_is_none() {
+        // no lines with the prefix, and lines with it that don't name a day
+        assert_eq!(insert_day_line(MEMBERS, 1, "aoc01 = {}", "aoc"), None);
+        assert_eq!(
+            insert_day_line("\"aoc\",\n", 1, "\"01/aoc01\",", "\""),
+            None
+        );
+        assert_eq!(insert_fuzz_bin("[dependencies]\n", 1), None);
+    }
+
+    #[test]
+    fn days_already_listed_are_refused() {
+        assert_eq!(add_member(2), None);
+        let fuzz = FUZZ_BIN_TEMPLATE.replace("NN", "02");
+        assert_eq!(insert_fuzz_bin(&fuzz, 2), None);
+    }
+
+    #[test]
+    fn fuzz_bins_go_in_order() {
+        let bin = |day: &str| FUZZ_BIN_TEMPLATE.replace("NN", day);
+        let text = format!("[workspace]\n\n{}\n{}", bin("02"), bin("04"));
+        assert_eq!(
+            insert_fuzz_bin(&text, 3).unwrap(),
+            format!("[workspace]\n\n{}\n{}\n{}", bin("02"), bin("03"), bin("04"))
+        );
+        assert_eq!(
+            insert_fuzz_bin(&text, 7).unwrap(),
+            format!("[workspace]\n\n{}\n{}\n{}", bin("02"), bin("04"), bin("07"))
+        );
+    }
+
+    /// just enough of the workspace for `new_day` to register day 1 in
+    fn workspace() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let files = [
+            ("Cargo.toml", MEMBERS.to_string()),
+            (
+                "aoc/Cargo.toml",
+                "aoc02 = { path = \"../02/aoc02\" }\n".to_string(),
+            ),
+            ("aoc/src/lib.rs", "pub use aoc02;\n".to_string()),
+            (
+                "aoc/src/registry.rs",
+                "    entry::<aoc02::Day02>(aoc02::generate::generate),\n".to_string(),
+            ),
+            (
+                "fuzz/Cargo.toml",
+                format!(
+                    "aoc02 = {{ path = \"../02/aoc02\" }}\n\n{}",
+                    FUZZ_BIN_TEMPLATE.replace("NN", "02")
+                ),
+            ),
+        ];
+        for (path, text) in files {
+            let path = dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        fs::create_dir(dir.path().join("fuzz/fuzz_targets")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn new_day_registers_everywhere() {
+        let dir = workspace();
+        let root = dir.path();
+        new_day(root, 1).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("01/aoc01/src/lib.rs").contains("const DAY: u32 = 1;"));
+        assert!(read("Cargo.toml").contains("    \"01/aoc01\",\n    \"02/aoc02\","));
+        assert!(read("aoc/src/lib.rs").starts_with("pub use aoc01;\n"));
+        assert!(read("aoc/src/registry.rs").starts_with("    entry::<aoc01::Day01>"));
+        assert!(read("fuzz/Cargo.toml").contains("name = \"day01\""));
+        assert!(read("fuzz/fuzz_targets/day01.rs").contains("aoc01::Day01"));
+        assert!(root.join("inputs/day01.example.txt").exists());
+    }
+
+    #[test]
+    fn existing_days_are_left_alone() {
+        let dir = workspace();
+        let root = dir.path();
+        fs::create_dir_all(root.join("02/aoc02")).unwrap();
+        let error = new_day(root, 2).unwrap_err();
+        assert!(error.to_string().contains("already exists"), "{}", error);
+
+        // registered but with its crate missing: nothing gets written at all
+        fs::remove_dir_all(root.join("02")).unwrap();
+        let error = new_day(root, 2).unwrap_err();
+        assert!(error.to_string().contains("there already"), "{}", error);
+        assert!(!root.join("02").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            MEMBERS
+        );
+    }
+
+    #[test]
+    fn failed_writes_name_the_file() {
+        let dir = workspace();
+        let root = dir.path();
+        fs::remove_dir(root.join("fuzz/fuzz_targets")).unwrap();
+        let error = new_day(root, 12).unwrap_err();
+        assert!(
+            error.to_string().contains("fuzz_targets/day12.rs"),
+            "{}",
+            error
+        );
+    }
+}
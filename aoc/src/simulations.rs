@@ -0,0 +1,402 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context};
+
+use aoc_common::{Cell, Colour, Grid, Input, Picture, Pos, Solution, Style};
+
+use aoc06::{Board, Day06, Guard, Patrol, Space};
+use aoc09::{Compaction, Day09, FileMove};
+use aoc11::{Blink, Day11, Stones};
+
+/// a day's simulation, the way the debugger steps through it
+pub trait Simulation {
+    type Snapshot;
+
+    /// what's being simulated, for the title
+    const NAME: &'static str;
+    /// the breakpoints it understands, besides `step:N`, for when it's given others
+    const BREAKPOINTS: &'static str;
+
+    /// the snapshots from the very start, the same every time
+    fn run(&self) -> Box<dyn Iterator<Item = Self::Snapshot> + '_>;
+    fn draw(&self, snapshot: &Self::Snapshot) -> Picture;
+    /// a line about what just happened
+    fn describe(&self, snapshot: &Self::Snapshot) -> String;
+    /// where the action is, to keep in view on a picture too big for the terminal
+    fn focus(&self, snapshot: &Self::Snapshot) -> Option<Pos>;
+    fn understands(&self, breakpoint: &Breakpoint) -> bool;
+    /// whether the simulation should stop at this snapshot
+    fn hits(&self, breakpoint: &Breakpoint, snapshot: &Self::Snapshot) -> bool;
+}
+
+/// somewhere to stop when playing a simulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// that many steps in
+    Step(usize),
+    /// whenever the guard turns
+    Turn,
+    /// when the file with that id moves
+    FileMoves(usize),
+    /// once there are more stones than that
+    StonesExceed(u64),
+}
+
+impl FromStr for Breakpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let (name, value) = s.split_once(':').unwrap_or((s, ""));
+        let number = || {
+            value
+                .parse::<u64>()
+                .with_context(|| format!("`{}` needs a number after the colon", name))
+        };
+        Ok(match name {
+            "turn" if value.is_empty() => Breakpoint::Turn,
+            "step" => Breakpoint::Step(usize::try_from(number()?)?),
+            "move" => Breakpoint::FileMoves(usize::try_from(number()?)?),
+            "stones" => Breakpoint::StonesExceed(number()?),
+            _ => {
+                return Err(anyhow!(
+                    "unknown breakpoint `{}`, expected turn, move:ID, stones:COUNT or step:N",
+                    s
+                ))
+            }
+        })
+    }
+}
+
+/// the same way they're written on the command line
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Step(n) => write!(f, "step:{}", n),
+            Breakpoint::Turn => write!(f, "turn"),
+            Breakpoint::FileMoves(id) => write!(f, "move:{}", id),
+            Breakpoint::StonesExceed(count) => write!(f, "stones:{}", count),
+        }
+    }
+}
+
+/// day 6's guard walking out of the lab
+pub struct GuardPatrol {
+    board: Board,
+    guard: Guard,
+    /// the step the guard first got to each spot on, shared by every snapshot so none
+    /// of them needs a copy of the map
+    first_visits: Grid<Option<usize>>,
+}
+
+impl GuardPatrol {
+    pub fn new(input: &Input) -> anyhow::Result<Self> {
+        let (board, guard) = Day06::parse(&input.text).map_err(|e| e.in_file(&input.source))?;
+        let mut first_visits = board.map(|_| None);
+        for patrol in aoc06::patrol(&board, &guard).filter(|patrol| patrol.first_visit) {
+            first_visits[patrol.guard.pos()] = Some(patrol.step);
+        }
+        Ok(GuardPatrol {
+            board,
+            guard,
+            first_visits,
+        })
+    }
+}
+
+impl Simulation for GuardPatrol {
+    type Snapshot = Patrol;
+
+    const NAME: &'static str = "day 6: the guard's patrol";
+    const BREAKPOINTS: &'static str = "turn";
+
+    fn run(&self) -> Box<dyn Iterator<Item = Patrol> + '_> {
+        Box::new(aoc06::patrol(&self.board, &self.guard))
+    }
+
+    fn draw(&self, patrol: &Patrol) -> Picture {
+        Picture::draw(&self.board, |pos, space| {
+            if pos == patrol.guard.pos() {
+                Cell::new(patrol.guard.symbol(), Style::fg(Colour::Red).bold())
+            } else if self.first_visits[pos].is_some_and(|step| step <= patrol.step) {
+                Cell::new('X', Style::fg(Colour::Cyan))
+            } else if *space == Space::Obstacle {
+                Cell::new('#', Style::fg(Colour::White))
+            } else {
+                Cell::new('.', Style::fg(Colour::Grey))
+            }
+        })
+    }
+
+    fn describe(&self, patrol: &Patrol) -> String {
+        let pos = patrol.guard.pos();
+        let action = if patrol.turned { "turns" } else { "is" };
+        format!(
+            "the guard {} at ({}, {}) facing {:?}, {} spots visited",
+            action,
+            pos.x,
+            pos.y,
+            patrol.guard.direction(),
+            patrol.visited
+        )
+    }
+
+    fn focus(&self, patrol: &Patrol) -> Option<Pos> {
+        Some(patrol.guard.pos())
+    }
+
+    fn understands(&self, breakpoint: &Breakpoint) -> bool {
+        matches!(breakpoint, Breakpoint::Turn)
+    }
+
+    fn hits(&self, breakpoint: &Breakpoint, patrol: &Patrol) -> bool {
+        matches!(breakpoint, Breakpoint::Turn) && patrol.turned
+    }
+}
+
+/// how many blocks of the disk go on each row of the picture
+const DISK_WIDTH: usize = 64;
+
+/// day 9's disk being compacted a whole file at a time
+pub struct FileCompaction {
+    lengths: Vec<usize>,
+    /// every file's turn, in order, so a step's disk can be laid out from the ones before it
+    moves: Vec<FileMove>,
+}
+
+impl FileCompaction {
+    pub fn new(input: &Input) -> anyhow::Result<Self> {
+        let lengths = Day09::parse(&input.text).map_err(|e| e.in_file(&input.source))?;
+        let moves = aoc09::compaction(&lengths)
+            .filter_map(|compaction| compaction.last)
+            .collect();
+        Ok(FileCompaction { lengths, moves })
+    }
+}
+
+impl Simulation for FileCompaction {
+    type Snapshot = Compaction;
+
+    const NAME: &'static str = "day 9: compacting whole files";
+    const BREAKPOINTS: &'static str = "move:ID";
+
+    fn run(&self) -> Box<dyn Iterator<Item = Compaction> + '_> {
+        Box::new(aoc09::compaction(&self.lengths))
+    }
+
+    fn draw(&self, compaction: &Compaction) -> Picture {
+        let moved = compaction.last.filter(|last| last.to.is_some());
+        let blocks = aoc09::layout(&self.lengths, self.moves[..compaction.step].iter().copied());
+        let rows = blocks.len().div_ceil(DISK_WIDTH).max(1);
+        let cells = (0..rows * DISK_WIDTH)
+            .map(|i| match blocks.get(i) {
+                None => Cell::plain(' '),
+                Some(None) => Cell::new('.', Style::fg(Colour::Grey)),
+                Some(Some(id)) => {
+                    // the last digit of the id, in a colour to tell neighbours apart
+                    let ch = char::from_digit((id % 10) as u32, 10).unwrap_or('?');
+                    let style = Style::fg(Colour::PALETTE[id % Colour::PALETTE.len()]);
+                    match moved {
+                        Some(last) if last.id == *id => {
+                            Cell::new(ch, style.on(Colour::Grey).bold())
+                        }
+                        _ => Cell::new(ch, style),
+                    }
+                }
+            })
+            .collect();
+        let disk = Grid::new(DISK_WIDTH, rows, cells);
+        Picture::draw(&disk, |_, cell| *cell)
+    }
+
+    fn describe(&self, compaction: &Compaction) -> String {
+        let what = match compaction.last {
+            None => String::from("the disk as the map lays it out"),
+            Some(last) => match last.to {
+                Some(to) => format!("file {} moves from block {} to {}", last.id, last.from, to),
+                None => format!(
+                    "file {} stays at block {}, nothing fits it",
+                    last.id, last.from
+                ),
+            },
+        };
+        format!("{}, checksum {}", what, compaction.checksum)
+    }
+
+    fn focus(&self, compaction: &Compaction) -> Option<Pos> {
+        let last = compaction.last?;
+        let block = last.to.unwrap_or(last.from);
+        Some(Pos::new(block % DISK_WIDTH, block / DISK_WIDTH))
+    }
+
+    fn understands(&self, breakpoint: &Breakpoint) -> bool {
+        matches!(breakpoint, Breakpoint::FileMoves(_))
+    }
+
+    fn hits(&self, breakpoint: &Breakpoint, compaction: &Compaction) -> bool {
+        match (breakpoint, compaction.last) {
+            (Breakpoint::FileMoves(id), Some(last)) => last.id == *id && last.to.is_some(),
+            _ => false,
+        }
+    }
+}
+
+/// day 11's stones changing every blink
+pub struct StoneBlinking {
+    stones: Stones,
+}
+
+impl StoneBlinking {
+    pub fn new(input: &Input) -> anyhow::Result<Self> {
+        let stones = Day11::parse(&input.text).map_err(|e| e.in_file(&input.source))?;
+        Ok(StoneBlinking { stones })
+    }
+}
+
+impl Simulation for StoneBlinking {
+    type Snapshot = Blink;
+
+    const NAME: &'static str = "day 11: blinking at stones";
+    const BREAKPOINTS: &'static str = "stones:COUNT";
+
+    fn run(&self) -> Box<dyn Iterator<Item = Blink> + '_> {
+        Box::new(self.stones.blinking())
+    }
+
+    /// a table of the numbers on the stones, most common first
+    fn draw(&self, blink: &Blink) -> Picture {
+        let mut stones = blink.stones.iter().collect::<Vec<_>>();
+        stones.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let lines = [(format!("{:>20} {:>20}", "number", "stones"), true)]
+            .into_iter()
+            .chain(
+                stones
+                    .into_iter()
+                    .map(|(stone, count)| (format!("{:>20} {:>20}", stone, count), false)),
+            )
+            .collect::<Vec<_>>();
+
+        let width = lines.iter().map(|(line, _)| line.len()).max().unwrap_or(0);
+        let cells = lines
+            .iter()
+            .flat_map(|(line, header)| {
+                let style = if *header {
+                    Style::PLAIN.bold()
+                } else {
+                    Style::PLAIN
+                };
+                format!("{:<width$}", line, width = width)
+                    .chars()
+                    .map(move |ch| Cell::new(ch, style))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let table = Grid::new(width, lines.len(), cells);
+        Picture::draw(&table, |_, cell| *cell)
+    }
+
+    fn describe(&self, blink: &Blink) -> String {
+        format!(
+            "after {} blinks there are {} stones with {} different numbers",
+            blink.blinks,
+            blink.count(),
+            blink.stones.len()
+        )
+    }
+
+    fn focus(&self, _blink: &Blink) -> Option<Pos> {
+        None
+    }
+
+    fn understands(&self, breakpoint: &Breakpoint) -> bool {
+        matches!(breakpoint, Breakpoint::StonesExceed(_))
+    }
+
+    fn hits(&self, breakpoint: &Breakpoint, blink: &Blink) -> bool {
+        matches!(breakpoint, Breakpoint::StonesExceed(limit) if blink.count() > *limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(day: u32) -> Input {
+        let path = format!(
+            "{}/../inputs/day{:02}.example.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            day
+        );
+        aoc_common::read_input(Some(path.as_ref())).unwrap()
+    }
+
+    #[test]
+    fn breakpoints_read_back_the_way_they_print() {
+        for spec in ["turn", "step:12", "move:9", "stones:1000"] {
+            assert_eq!(spec.parse::<Breakpoint>().unwrap().to_string(), spec);
+        }
+        for bad in ["turn:3", "move", "move:x", "stones:-1", "jump"] {
+            assert!(bad.parse::<Breakpoint>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn breakpoints_stop_where_they_say() {
+        let patrol = GuardPatrol::new(&example(6)).unwrap();
+        let turn = patrol
+            .run()
+            .position(|p| patrol.hits(&Breakpoint::Turn, &p));
+        // the guard walks 5 spots north before the first obstacle
+        assert_eq!(turn, Some(6));
+
+        let disk = FileCompaction::new(&example(9)).unwrap();
+        let moves = disk
+            .run()
+            .filter(|c| (0..10).any(|id| disk.hits(&Breakpoint::FileMoves(id), c)))
+            .count();
+        assert_eq!(moves, 4);
+
+        let stones = StoneBlinking::new(&example(11)).unwrap();
+        let over = stones
+            .run()
+            .position(|b| stones.hits(&Breakpoint::StonesExceed(20), &b));
+        assert_eq!(over, Some(6));
+    }
+
+    #[test]
+    fn patrol_pictures_only_show_the_path_so_far() {
+        let patrol = GuardPatrol::new(&example(6)).unwrap();
+        let walked = |snapshot: &Patrol| {
+            let picture = patrol.draw(snapshot);
+            picture
+                .cells
+                .iter()
+                .filter(|(_, cell)| cell.ch == 'X')
+                .count()
+        };
+        let steps = patrol.run().collect::<Vec<Patrol>>();
+        assert_eq!(walked(&steps[0]), 0);
+        // 5 steps north, not counting the spot the guard is standing on
+        assert_eq!(walked(&steps[5]), 5);
+        assert_eq!(walked(steps.last().unwrap()), 40);
+    }
+
+    #[test]
+    fn disks_wrap_onto_rows() {
+        let disk = FileCompaction::new(&example(9)).unwrap();
+        let first = disk.run().next().unwrap();
+        let picture = disk.draw(&first);
+        assert_eq!(picture.cells.width(), DISK_WIDTH);
+        let text = picture.to_string();
+        assert!(text.starts_with("00...111...2...333.44.5555.6666.777.888899 "));
+    }
+
+    #[test]
+    fn disk_pictures_only_show_the_moves_so_far() {
+        let disk = FileCompaction::new(&example(9)).unwrap();
+        let steps = disk.run().collect::<Vec<Compaction>>();
+        let drawn = |step: usize| disk.draw(&steps[step]).to_string();
+        assert!(drawn(1).starts_with("0099.111...2...333.44.5555.6666.777.8888.. "));
+        assert!(drawn(10).starts_with("00992111777.44.333....5555.6666.....8888.. "));
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::bail;
+
+use aoc_common::Part;
+
+use crate::client::Client;
+use crate::history::{Guess, GuessHistory, Verdict};
+use crate::inputs;
+use aoc::registry::SOLUTIONS;
+
+/// submit an answer for one part of a day, working it out from the input if not given
+pub fn submit(
+    day: u32,
+    part: Part,
+    answer: Option<String>,
+    dirs: &[PathBuf],
+    history_path: &Path,
+    base_url: Option<&str>,
+) -> anyhow::Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(day, part, dirs)?,
+    };
+    let answer = answer.trim();
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        bail!("`{}` doesn't look like an answer", answer);
+    }
+
+    let mut history = GuessHistory::load(history_path)?;
+    if let Some(objection) = history.objection(day, part, answer) {
+        bail!("not submitting day {:02} part {}: {}", day, part, objection);
+    }
+
+    let client = Client::new(base_url)?;
+    let page = client.submit(day, part, answer)?;
+    let (verdict, wait) = parse_reply(&page)?;
+    history.record(Guess {
+        day,
+        part,
+        verdict,
+        answer: answer.to_string(),
+    })?;
+
+    match verdict {
+        Verdict::Right => {
+            println!("day {:02} part {}: {} is right", day, part, answer);
+            Ok(())
+        }
+        Verdict::Wait => bail!(
+            "{} wasn't judged, you have {} left to wait",
+            answer,
+            wait.unwrap_or_else(|| String::from("some time"))
+        ),
+        verdict => bail!("day {:02} part {}: {} is {}", day, part, answer, verdict),
+    }
+}
+
+/// run the solution against the day's input to get the answer to submit
+fn solve(day: u32, part: Part, dirs: &[PathBuf]) -> anyhow::Result<String> {
+    let Some(entry) = SOLUTIONS.iter().find(|e| e.day == day) else {
+        bail!("no solution registered for day {}", day);
+    };
+    let path = inputs::find_input(dirs, day, false)?;
+    let input = aoc_common::read_input(Some(&path))?;
+    let answer = (entry.run)(part, &input)?.answer;
+    println!("day {:02} part {}: {}", day, part, answer);
+    Ok(answer)
+}
+
+/// work out the verdict from the page the site sends back, along with how long to wait
+/// if it wasn't judged
+fn parse_reply(page: &str) -> anyhow::Result<(Verdict, Option<String>)> {
+    if page.contains("That's the right answer") {
+        Ok((Verdict::Right, None))
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok((Verdict::TooHigh, None))
+        } else if page.contains("your answer is too low") {
+            Ok((Verdict::TooLow, None))
+        } else {
+            Ok((Verdict::Wrong, None))
+        }
+    } else if page.contains("You gave an answer too recently") {
+        // "You have 42s left to wait."
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Ok((Verdict::Wait, wait))
+    } else if page.contains("You don't seem to be solving the right level") {
+        bail!("that part is either already solved or not unlocked yet")
+    } else {
+        bail!("couldn't make sense of the site's response to the answer")
+    }
+}
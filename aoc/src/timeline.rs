@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+
+/// how many of the latest snapshots to hold on to, so stepping back a little doesn't mean
+/// running the whole simulation again
+const KEEP: usize = 64;
+
+/// a simulation's snapshots, produced lazily, that can be gone through backwards as well
+/// as forwards. only a window of recent snapshots is kept, since some are big, and going
+/// back past it runs the simulation again from the start
+pub struct Timeline<'a, S> {
+    start: Box<dyn Fn() -> Box<dyn Iterator<Item = S> + 'a> + 'a>,
+    steps: Box<dyn Iterator<Item = S> + 'a>,
+    window: VecDeque<S>,
+    /// which step the first snapshot in the window is
+    window_start: usize,
+    /// which step is being looked at
+    cursor: usize,
+    /// whether `steps` has run out
+    finished: bool,
+}
+
+impl<'a, S> Timeline<'a, S> {
+    /// a timeline at the first snapshot `start` makes, or `None` if it makes none. it has
+    /// to make the same snapshots every time it's called
+    pub fn new(start: impl Fn() -> Box<dyn Iterator<Item = S> + 'a> + 'a) -> Option<Self> {
+        let mut steps = start();
+        let first = steps.next()?;
+        Some(Timeline {
+            start: Box::new(start),
+            steps,
+            window: VecDeque::from([first]),
+            window_start: 0,
+            cursor: 0,
+            finished: false,
+        })
+    }
+
+    pub fn current(&self) -> &S {
+        &self.window[self.cursor - self.window_start]
+    }
+
+    /// which step is being looked at, counting the first snapshot as step 0
+    pub fn index(&self) -> usize {
+        self.cursor
+    }
+
+    /// move on a step, unless the simulation is already over
+    pub fn forward(&mut self) -> bool {
+        if self.cursor + 1 < self.window_start + self.window.len() {
+            self.cursor += 1;
+            return true;
+        }
+        if self.finished {
+            return false;
+        }
+        match self.steps.next() {
+            Some(snapshot) => {
+                self.push(snapshot);
+                self.cursor += 1;
+                true
+            }
+            None => {
+                self.finished = true;
+                false
+            }
+        }
+    }
+
+    /// go back a step, unless this is the first one
+    pub fn back(&mut self) -> bool {
+        match self.cursor.checked_sub(1) {
+            Some(index) => {
+                self.go_to(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// jump to a step, or to the last one if the simulation ends before it
+    pub fn go_to(&mut self, index: usize) {
+        if index < self.window_start {
+            self.replay(index);
+        } else if index < self.window_start + self.window.len() {
+            self.cursor = index;
+        } else {
+            while self.cursor < index && self.forward() {}
+        }
+    }
+
+    /// start the simulation over and run it up to `index`, keeping the window that ends
+    /// there
+    fn replay(&mut self, index: usize) {
+        self.steps = (self.start)();
+        self.window.clear();
+        self.window_start = 0;
+        self.finished = false;
+        for _ in 0..=index {
+            match self.steps.next() {
+                Some(snapshot) => self.push(snapshot),
+                None => break,
+            }
+        }
+        self.cursor = self.window_start + self.window.len() - 1;
+    }
+
+    fn push(&mut self, snapshot: S) {
+        self.window.push_back(snapshot);
+        if self.window.len() > KEEP {
+            self.window.pop_front();
+            self.window_start += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counting(to: usize) -> Timeline<'static, usize> {
+        Timeline::new(move || Box::new(0..to)).unwrap()
+    }
+
+    #[test]
+    fn steps_both_ways() {
+        let mut timeline = counting(3);
+        assert_eq!(*timeline.current(), 0);
+        assert!(!timeline.back());
+        assert!(timeline.forward());
+        assert!(timeline.forward());
+        assert!(!timeline.forward());
+        assert_eq!((timeline.index(), *timeline.current()), (2, 2));
+        assert!(timeline.back());
+        assert_eq!(*timeline.current(), 1);
+        assert!(timeline.forward());
+        assert!(!timeline.forward());
+    }
+
+    #[test]
+    fn going_back_past_the_window_starts_over() {
+        let mut timeline = counting(1000);
+        timeline.go_to(500);
+        assert_eq!(*timeline.current(), 500);
+        assert_eq!(timeline.window_start, 500 - KEEP + 1);
+
+        timeline.go_to(10);
+        assert_eq!((timeline.index(), *timeline.current()), (10, 10));
+        for expected in (0..10).rev() {
+            assert!(timeline.back());
+            assert_eq!(*timeline.current(), expected);
+        }
+        timeline.go_to(2000);
+        assert_eq!(*timeline.current(), 999);
+    }
+
+    #[test]
+    fn empty_simulations_have_no_timeline() {
+        assert!(Timeline::<usize>::new(|| Box::new(0..0)).is_none());
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::bail;
+
+use aoc_common::Part;
+
+use crate::answers::{input_hash, AnswerBook};
+use crate::inputs;
+use aoc::registry::{Entry, SOLUTIONS};
+
+/// check every selected solution against the answers book and write a table of results to `out`
+pub fn verify(
+    out: &mut impl Write,
+    day: Option<u32>,
+    dirs: &[PathBuf],
+    answers_path: &Path,
+    record: bool,
+) -> anyhow::Result<()> {
+    let entries = SOLUTIONS
+        .iter()
+        .filter(|e| day.is_none_or(|d| e.day == d))
+        .collect::<Vec<&Entry>>();
+    if entries.is_empty() {
+        bail!("no solution registered for day {}", day.unwrap_or_default());
+    }
+
+    let mut book = AnswerBook::load(answers_path)?;
+    let mut failed = 0;
+    let mut recorded = 0;
+
+    writeln!(
+        out,
+        "{:<4} {:<5} {:<9} {:<20} expected",
+        "day", "part", "status", "answer"
+    )?;
+    for entry in entries {
+        let Ok(path) = inputs::find_input(dirs, entry.day, false) else {
+            writeln!(
+                out,
+                "{:<4} {:<5} {:<9} no {} found",
+                entry.day,
+                "-",
+                "skipped",
+                inputs::file_name(entry.day, false)
+            )?;
+            continue;
+        };
+        // a bad input only fails its own day, like a solution that errors
+        let input = match aoc_common::read_input(Some(&path)) {
+            Ok(input) => input,
+            Err(e) => {
+                failed += 1;
+                writeln!(
+                    out,
+                    "{:<4} {:<5} {:<9} {:<20} {:#}",
+                    entry.day, "-", "ERROR", "", e
+                )?;
+                continue;
+            }
+        };
+        let hash = input_hash(&input.text);
+
+        for part in Part::BOTH {
+            let (answer, status, detail) = match (entry.run)(part, &input).map(|o| o.answer) {
+                Err(e) => {
+                    failed += 1;
+                    (String::new(), "ERROR", format!("{:#}", e))
+                }
+                Ok(answer) => match book.get(entry.day, part, &hash).map(str::to_string) {
+                    Some(expected) if expected == answer => (answer, "pass", String::new()),
+                    Some(expected) => {
+                        failed += 1;
+                        (answer, "FAIL", expected)
+                    }
+                    None if record => {
+                        book.insert(entry.day, part, &hash, &answer);
+                        recorded += 1;
+                        (answer, "recorded", String::new())
+                    }
+                    None => (answer, "unknown", String::from("(use --record to store)")),
+                },
+            };
+            writeln!(
+                out,
+                "{:<4} {:<5} {:<9} {:<20} {}",
+                entry.day,
+                part.number(),
+                status,
+                answer,
+                detail
+            )?;
+        }
+    }
+
+    if recorded > 0 {
+        book.save()?;
+        writeln!(
+            out,
+            "recorded {} new answers in {}",
+            recorded,
+            answers_path.display()
+        )?;
+    }
+    if failed > 0 {
+        bail!("{} solutions did not match their known answers", failed);
+    }
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use aoc_common::Part;
+
+use crate::inputs;
+use crate::report::Record;
+
+/// how long things have to stay quiet before a burst of changes counts as done, since
+/// editors tend to write a file in several steps
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// rebuild and re-run a day every time its source or input changes
+pub fn watch(
+    day: u32,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    example: bool,
+    dirs: &[PathBuf],
+    release: bool,
+) -> anyhow::Result<()> {
+    let root = env::current_dir()
+        .ok()
+        .and_then(|d| inputs::workspace_root(&d))
+        .context("aoc watch has to be run from inside the workspace")?;
+    let src = root.join(format!("{:02}/aoc{:02}/src", day, day));
+    if !src.is_dir() {
+        bail!("no source for day {} at {}", day, src.display());
+    }
+    let input = match input {
+        Some(path) => path,
+        None => inputs::find_input(dirs, day, example)?,
+    };
+
+    // compare against canonical paths, since that's what change events come back with
+    let src = src
+        .canonicalize()
+        .with_context(|| format!("failed to find {}", src.display()))?;
+    let input = input
+        .canonicalize()
+        .with_context(|| format!("failed to find {}", input.display()))?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher
+        .watch(&src, RecursiveMode::Recursive)
+        .with_context(|| format!("failed to watch {}", src.display()))?;
+    // editors often replace a file rather than write to it, which a watch on the file
+    // itself wouldn't survive
+    let input_dir = input.parent().unwrap_or(Path::new("/"));
+    watcher
+        .watch(input_dir, RecursiveMode::NonRecursive)
+        .with_context(|| format!("failed to watch {}", input_dir.display()))?;
+
+    println!(
+        "watching {} and {}, ctrl-c to stop",
+        src.display(),
+        input.display()
+    );
+    let mut previous = HashMap::new();
+    let runner = Runner {
+        root,
+        day,
+        part,
+        input: input.clone(),
+        release,
+    };
+    loop {
+        runner.run(&mut previous)?;
+        wait_for_change(&rx, &src, &input)?;
+        println!();
+        println!("change detected, rebuilding");
+    }
+}
+
+/// block until something under `src` or the input itself changes, then until things
+/// settle down
+fn wait_for_change(
+    rx: &Receiver<notify::Result<Event>>,
+    src: &Path,
+    input: &Path,
+) -> anyhow::Result<()> {
+    loop {
+        let event = rx.recv().context("file watcher stopped")??;
+        let relevant = !matches!(event.kind, EventKind::Access(_))
+            && event.paths.iter().any(|p| p.starts_with(src) || p == input);
+        if relevant {
+            break;
+        }
+    }
+    while rx.recv_timeout(SETTLE_TIME).is_ok() {}
+    Ok(())
+}
+
+/// runs the day through a fresh build of the dispatcher
+struct Runner {
+    root: PathBuf,
+    day: u32,
+    part: Option<Part>,
+    input: PathBuf,
+    release: bool,
+}
+
+impl Runner {
+    /// run the day and print each answer next to the one from the last run
+    fn run(&self, previous: &mut HashMap<u32, String>) -> anyhow::Result<()> {
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut command = Command::new(cargo);
+        command
+            .current_dir(&self.root)
+            .args(["run", "--quiet", "--package", "aoc"]);
+        if self.release {
+            command.arg("--release");
+        }
+        command
+            .args(["--", "run", "--format", "ndjson", "--day"])
+            .arg(self.day.to_string())
+            .arg("--input")
+            .arg(&self.input);
+        if let Some(part) = self.part {
+            command.arg("--part").arg(part.to_string());
+        }
+
+        // build errors go straight through to the terminal
+        let output = command
+            .stderr(Stdio::inherit())
+            .output()
+            .context("failed to run cargo")?;
+        let records = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Record>(line).ok())
+            .collect::<Vec<Record>>();
+        if records.is_empty() {
+            println!("build or run failed, waiting for the next change");
+            return Ok(());
+        }
+
+        for record in records {
+            let label = format!("day {:02} part {}", record.day, record.part.unwrap_or(0));
+            let Some(answer) = record.answer.as_ref() else {
+                println!(
+                    "{}: error: {}",
+                    label,
+                    record.error.as_deref().unwrap_or("unknown")
+                );
+                continue;
+            };
+
+            let part = record.part.unwrap_or(0);
+            let change = match previous.get(&part) {
+                None => String::new(),
+                Some(old) if old == answer => String::from(" (unchanged)"),
+                Some(old) => format!(" (was {})", old),
+            };
+            println!(
+                "{}: {}{}    parse {:.2?}, solve {:.2?}",
+                label,
+                answer,
+                change,
+                record.parse_time(),
+                record.solve_time()
+            );
+            previous.insert(part, answer.clone());
+        }
+        Ok(())
+    }
+}
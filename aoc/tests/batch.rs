@@ -0,0 +1,37 @@
+mod common;
+
+use std::fs;
+
+use common::aoc;
+
+const DAY01: &str = include_str!("../../inputs/day01.example.txt");
+
+#[test]
+fn directories_only_give_up_the_days_inputs() {
+    let dir = tempfile::tempdir().unwrap();
+    let inputs = dir.path().join("inputs");
+    fs::create_dir(&inputs).unwrap();
+    for name in ["day01.txt", "day01.example.txt", "alice.txt"] {
+        fs::write(inputs.join(name), DAY01).unwrap();
+    }
+    // none of these are day 1 inputs, and most wouldn't even parse as one
+    fs::write(inputs.join("answers.txt"), "1 1 abc 11\n").unwrap();
+    fs::write(inputs.join("guesses.txt"), "1 1 11 right\n").unwrap();
+    fs::write(inputs.join("day03.txt"), "mul(2,4)\n").unwrap();
+    fs::write(inputs.join("notes.md"), "# notes\n").unwrap();
+
+    let output = aoc(dir.path())
+        .args(["run", "--day", "1", "--part", "1", "--input"])
+        .arg(&inputs)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    let files = stdout
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_whitespace().next())
+        .map(|path| path.rsplit('/').next().unwrap())
+        .collect::<Vec<&str>>();
+    assert_eq!(files, ["alice.txt", "day01.example.txt", "day01.txt"]);
+}
@@ -0,0 +1,124 @@
+//! helpers shared by the integration tests: a stand-in for the puzzle site and a way to run
+//! the binary without picking up the real environment
+
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use tiny_http::{Header, Response, Server};
+
+/// a request the mock server got
+#[derive(Debug, Clone)]
+pub struct Seen {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// what the mock server should answer with
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+    pub headers: Vec<(&'static str, String)>,
+}
+
+impl Reply {
+    pub fn new(status: u16, body: &str) -> Self {
+        Reply {
+            status,
+            body: body.to_string(),
+            headers: Vec::new(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &'static str, value: &str) -> Self {
+        self.headers.push((name, value.to_string()));
+        self
+    }
+}
+
+/// a local http server that answers every request with whatever the handler says
+pub struct MockServer {
+    pub url: String,
+    server: Arc<Server>,
+    seen: Arc<Mutex<Vec<Seen>>>,
+}
+
+impl MockServer {
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&Seen) -> Reply + Send + 'static,
+    {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("failed to start mock server"));
+        let url = format!("http://{}", server.server_addr());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let (server_ref, seen_ref) = (Arc::clone(&server), Arc::clone(&seen));
+        thread::spawn(move || {
+            // recv fails once the server is unblocked on drop
+            while let Ok(mut request) = server_ref.recv() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let request_seen = Seen {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie: request
+                        .headers()
+                        .iter()
+                        .find(|h| h.field.equiv("Cookie"))
+                        .map(|h| h.value.to_string()),
+                    body,
+                };
+
+                let reply = handler(&request_seen);
+                seen_ref.lock().unwrap().push(request_seen);
+
+                let mut response = Response::from_string(reply.body).with_status_code(reply.status);
+                for (name, value) in reply.headers {
+                    response.add_header(Header::from_bytes(name, value).unwrap());
+                }
+                let _ = request.respond(response);
+            }
+        });
+
+        MockServer { url, server, seen }
+    }
+
+    /// everything the server has been asked so far
+    pub fn requests(&self) -> Vec<Seen> {
+        self.seen.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
+
+/// the aoc binary, cut off from the real session token, site and inputs
+pub fn aoc(config_dir: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_BASE_URL")
+        .env_remove("AOC_INPUT_DIR")
+        .env_remove("RUST_LOG")
+        .env("XDG_CONFIG_HOME", config_dir);
+    command
+}
+
+/// run a command whose stdout is closed before it gets to write anything, like `aoc ... | head -c0`
+pub fn into_closed_pipe(command: &mut Command) -> Output {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    drop(child.stdout.take());
+    child.wait_with_output().unwrap()
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, MockServer, Reply};
+
+#[test]
+fn downloads_and_caches_an_input() {
+    let server = MockServer::start(|_| Reply::new(200, "1 2\n3 4\n"));
+    let dir = tempfile::tempdir().unwrap();
+    let inputs = dir.path().join("inputs");
+
+    for _ in 0..2 {
+        let output = aoc(dir.path())
+            .args(["fetch", "--day", "3", "--base-url", &server.url])
+            .arg("--input-dir")
+            .arg(&inputs)
+            .env("AOC_SESSION", "abc123")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{:?}", output);
+    }
+
+    assert_eq!(
+        fs::read_to_string(inputs.join("day03.txt")).unwrap(),
+        "1 2\n3 4\n"
+    );
+
+    // the second run found the cached copy and never asked again
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, "/2024/day/3/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+}
+
+#[test]
+fn reads_the_session_from_the_config_file() {
+    let server = MockServer::start(|_| Reply::new(200, "input\n"));
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("aoc")).unwrap();
+    fs::write(dir.path().join("aoc/session"), "from-file\n").unwrap();
+
+    let output = aoc(dir.path())
+        .args(["fetch", "--day", "1", "--base-url", &server.url])
+        .arg("--input-dir")
+        .arg(dir.path().join("inputs"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        server.requests()[0].cookie.as_deref(),
+        Some("session=from-file")
+    );
+}
+
+#[test]
+fn missing_session_is_an_error() {
+    let server = MockServer::start(|_| Reply::new(200, "input\n"));
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(dir.path())
+        .args(["fetch", "--day", "1", "--base-url", &server.url])
+        .arg("--input-dir")
+        .arg(dir.path().join("inputs"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("AOC_SESSION"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn locked_day_is_a_clear_error() {
+    let server = MockServer::start(|_| Reply::new(404, "Please don't repeatedly request this"));
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(dir.path())
+        .args(["fetch", "--day", "25", "--base-url", &server.url])
+        .arg("--input-dir")
+        .arg(dir.path().join("inputs"))
+        .env("AOC_SESSION", "abc123")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("isn't unlocked yet"));
+    assert!(!dir.path().join("inputs/day25.txt").exists());
+}
+
+#[test]
+fn rate_limit_is_a_clear_error() {
+    let server =
+        MockServer::start(|_| Reply::new(429, "slow down").with_header("Retry-After", "60"));
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = aoc(dir.path())
+        .args(["fetch", "--day", "2", "--base-url", &server.url])
+        .arg("--input-dir")
+        .arg(dir.path().join("inputs"))
+        .env("AOC_SESSION", "abc123")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("rate limited"), "{}", stderr);
+    assert!(stderr.contains("60 seconds"), "{}", stderr);
+}
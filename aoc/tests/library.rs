@@ -0,0 +1,24 @@
+use aoc::aoc07::Day07;
+use aoc::{Part, Solution};
+
+const DAY01: &str = include_str!("../../inputs/day01.example.txt");
+const DAY07: &str = include_str!("../../inputs/day07.example.txt");
+
+#[test]
+fn solves_a_day_by_number() {
+    assert_eq!(aoc::solve(1, Part::One, DAY01).unwrap(), "11");
+    assert_eq!(aoc::solve(1, Part::Two, DAY01).unwrap(), "31");
+    assert_eq!(aoc::days().next(), Some(1));
+}
+
+#[test]
+fn typed_answers_through_a_days_crate() {
+    assert_eq!(Day07::answer(Part::Two, DAY07).unwrap(), 11387);
+}
+
+#[test]
+fn bad_input_and_unknown_days_are_errors() {
+    let error = aoc::solve(1, Part::One, "1 x\n").unwrap_err();
+    assert!(error.to_string().contains("expected number"), "{}", error);
+    assert!(aoc::solve(26, Part::One, DAY01).is_err());
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use std::path::Path;
+use std::process::Output;
+
+use common::aoc;
+
+fn run_day5(args: &[&str], rust_log: Option<&str>) -> Output {
+    let dir = tempfile::tempdir().unwrap();
+    let input = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs/day05.example.txt");
+    let mut command = aoc(dir.path());
+    command
+        .args(args)
+        .args(["run", "--day", "5", "--part", "1", "--input"]);
+    command.arg(input);
+    if let Some(filter) = rust_log {
+        command.env("RUST_LOG", filter);
+    }
+    command.output().unwrap()
+}
+
+#[test]
+fn quiet_unless_asked() {
+    let output = run_day5(&[], None);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn verbose_logs_decisions_inside_spans() {
+    let output = run_day5(&["-v"], None);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("solve{day=5 part=1}: aoc05: update 4 violates rule 97|75"),
+        "{}",
+        stderr
+    );
+    // the answer still goes to stdout on its own
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "143\n");
+}
+
+#[test]
+fn rust_log_filters_by_crate() {
+    let output = run_day5(&[], Some("aoc05=debug"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("violates rule"));
+    let output = run_day5(&["-v"], Some("aoc05=off"));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("violates rule"));
+}
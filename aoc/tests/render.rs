@@ -0,0 +1,17 @@
+mod common;
+
+use common::{aoc, into_closed_pipe};
+
+#[test]
+fn closed_stdout_while_drawing_is_a_clean_exit() {
+    let dir = tempfile::tempdir().unwrap();
+    let example = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs/day06.example.txt");
+    let output = into_closed_pipe(
+        aoc(dir.path())
+            .args(["run", "--day", "6", "--render", "--input"])
+            .arg(example),
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
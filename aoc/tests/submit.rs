@@ -0,0 +1,138 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use common::{aoc, MockServer, Reply};
+
+const RIGHT: &str =
+    "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+const TOO_HIGH: &str =
+    "<article><p>That's not the right answer; your answer is too high.</p></article>";
+const TOO_LOW: &str =
+    "<article><p>That's not the right answer; your answer is too low.</p></article>";
+const WAIT: &str =
+    "<article><p>You gave an answer too recently. You have 42s left to wait.</p></article>";
+
+fn submit(server: &MockServer, dir: &Path, part: &str, answer: &str) -> Output {
+    submit_command(server, dir, part)
+        .args(["--answer", answer])
+        .output()
+        .unwrap()
+}
+
+fn submit_command(server: &MockServer, dir: &Path, part: &str) -> Command {
+    let mut command = aoc(dir);
+    command
+        .args([
+            "submit",
+            "--day",
+            "1",
+            "--part",
+            part,
+            "--base-url",
+            &server.url,
+        ])
+        .arg("--input-dir")
+        .arg(dir.join("inputs"))
+        .env("AOC_SESSION", "abc123");
+    command
+}
+
+fn history(dir: &Path) -> String {
+    fs::read_to_string(dir.join("inputs/guesses.txt")).unwrap()
+}
+
+#[test]
+fn right_answer_is_posted_and_recorded() {
+    let server = MockServer::start(|_| Reply::new(200, RIGHT));
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = submit(&server, dir.path(), "2", "42");
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("42 is right"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url, "/2024/day/1/answer");
+    assert_eq!(requests[0].body, "level=2&answer=42");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+    assert!(history(dir.path()).contains("01 2 right 42"));
+
+    // once solved there's nothing left to submit
+    let output = submit(&server, dir.path(), "2", "43");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already solved"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn refuses_known_wrong_and_out_of_bound_guesses() {
+    let server = MockServer::start(|seen| match seen.body.as_str() {
+        "level=1&answer=100" => Reply::new(200, TOO_HIGH),
+        _ => Reply::new(200, TOO_LOW),
+    });
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = submit(&server, dir.path(), "1", "100");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("100 is too high"));
+
+    let output = submit(&server, dir.path(), "1", "10");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("10 is too low"));
+    assert_eq!(server.requests().len(), 2);
+
+    // the same guess again, then guesses outside 10..100
+    for answer in ["100", "150", "10", "3"] {
+        let output = submit(&server, dir.path(), "1", answer);
+        assert!(!output.status.success());
+        assert!(
+            String::from_utf8_lossy(&output.stderr).contains("not submitting"),
+            "{:?}",
+            output
+        );
+    }
+    assert_eq!(server.requests().len(), 2);
+
+    // still fair game
+    submit(&server, dir.path(), "1", "50");
+    assert_eq!(server.requests().len(), 3);
+
+    let history = history(dir.path());
+    assert!(history.contains("01 1 high 100"));
+    assert!(history.contains("01 1 low 10"));
+    assert!(history.contains("01 1 low 50"));
+}
+
+#[test]
+fn waiting_guess_can_be_sent_again() {
+    let server = MockServer::start(|_| Reply::new(200, WAIT));
+    let dir = tempfile::tempdir().unwrap();
+
+    for _ in 0..2 {
+        let output = submit(&server, dir.path(), "1", "7");
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("42s left to wait"));
+    }
+    assert_eq!(server.requests().len(), 2);
+    assert!(history(dir.path()).contains("01 1 wait 7"));
+}
+
+#[test]
+fn works_out_the_answer_when_not_given() {
+    let server = MockServer::start(|_| Reply::new(200, RIGHT));
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("inputs")).unwrap();
+    fs::write(
+        dir.path().join("inputs/day01.txt"),
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    )
+    .unwrap();
+
+    let output = submit_command(&server, dir.path(), "1").output().unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(server.requests()[0].body, "level=1&answer=11");
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, into_closed_pipe};
+
+#[test]
+fn unreadable_input_only_fails_its_own_day() {
+    let dir = tempfile::tempdir().unwrap();
+    let inputs = dir.path().join("inputs");
+    fs::create_dir(&inputs).unwrap();
+    fs::write(inputs.join("day01.txt"), "\n").unwrap();
+    fs::write(
+        inputs.join("day02.txt"),
+        include_str!("../../inputs/day02.example.txt"),
+    )
+    .unwrap();
+
+    let output = aoc(dir.path())
+        .arg("verify")
+        .arg("--input-dir")
+        .arg(&inputs)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert!(stdout.contains("1    -     ERROR"), "{}", stdout);
+    assert!(stdout.contains("is empty"), "{}", stdout);
+    // the days after it still get checked
+    assert!(stdout.contains("2    1     unknown"), "{}", stdout);
+    assert!(stdout.contains("no day03.txt found"), "{}", stdout);
+}
+
+#[test]
+fn closed_stdout_is_a_clean_exit() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = into_closed_pipe(
+        aoc(dir.path())
+            .arg("verify")
+            .arg("--input-dir")
+            .arg(dir.path()),
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
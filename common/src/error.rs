@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// longest snippet we'll echo back before cutting it short
+const MAX_SNIPPET: usize = 30;
+
+/// where in the input a parse error was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// 1-based line and column, for inputs made of lines
+    Line { line: usize, column: usize },
+    /// 1-based character offset, for inputs that are one long line
+    Char(usize),
+    /// the input ran out before we found what we needed
+    End,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub location: Location,
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// an error on the line at `line_index` (0-based, as from `enumerate`), where `snippet`
+    /// is the offending slice of `line`. the column is worked out from where `snippet`
+    /// sits inside `line`, so pass a subslice rather than a copy.
+    pub fn at(line_index: usize, line: &str, snippet: &str, expected: impl Into<String>) -> Self {
+        let offset = (snippet.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() && line.is_char_boundary(offset) {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        ParseError {
+            file: None,
+            location: Location::Line {
+                line: line_index + 1,
+                column,
+            },
+            snippet: snippet.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// an error at the character at `index` (0-based) of a single line input
+    pub fn at_char(index: usize, snippet: impl Into<String>, expected: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            location: Location::Char(index + 1),
+            snippet: snippet.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// an error for input that stopped short
+    pub fn end_of_input(expected: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            location: Location::End,
+            snippet: String::new(),
+            expected: expected.into(),
+        }
+    }
+
+    /// attach the name of the file the input came from
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}: ", file)?;
+        }
+        match self.location {
+            Location::Line { line, column } => write!(f, "line {}, column {}", line, column)?,
+            Location::Char(index) => write!(f, "char {}", index)?,
+            Location::End => write!(f, "end of input")?,
+        }
+        write!(f, ": expected {}", self.expected)?;
+
+        if let Location::End = self.location {
+            return Ok(());
+        }
+        if self.snippet.chars().count() > MAX_SNIPPET {
+            let cut = self.snippet.chars().take(MAX_SNIPPET).collect::<String>();
+            write!(f, ", found `{}...`", cut)
+        } else {
+            write!(f, ", found `{}`", self.snippet)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// parse `token`, a slice of the line at `line_index`, as a number
+pub fn parse_num<T: FromStr>(line_index: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(line_index, line, token, "number"))
+}
@@ -0,0 +1,258 @@
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Pos;
+
+/// a signed step or point on the plane, with `y` growing downwards like rows do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// `self + other`, or `None` if either coordinate overflows
+    pub fn checked_add(self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// steps needed to get to `other` moving only across or down, which can be more than a u32 holds
+    pub fn manhattan(self, other: Vec2) -> u64 {
+        u64::from(self.x.abs_diff(other.x)) + u64::from(self.y.abs_diff(other.y))
+    }
+
+    /// steps needed to get to `other` when diagonal moves are allowed too
+    pub fn chebyshev(self, other: Vec2) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, n: i32) -> Vec2 {
+        Vec2::new(self.x * n, self.y * n)
+    }
+}
+
+/// fails for positions too far out to fit in an `i32`
+impl TryFrom<Pos> for Vec2 {
+    type Error = TryFromIntError;
+
+    fn try_from(pos: Pos) -> Result<Self, Self::Error> {
+        Ok(Vec2::new(i32::try_from(pos.x)?, i32::try_from(pos.y)?))
+    }
+}
+
+/// fails for anything left of or above the grid
+impl TryFrom<Vec2> for Pos {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Vec2) -> Result<Self, Self::Error> {
+        Ok(Pos::new(usize::try_from(v.x)?, usize::try_from(v.y)?))
+    }
+}
+
+/// the four ways to go along a row or column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// clockwise from north
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// one step this way
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction4::North => Vec2::new(0, -1),
+            Direction4::East => Vec2::new(1, 0),
+            Direction4::South => Vec2::new(0, 1),
+            Direction4::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+/// the eight ways to go, diagonals included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// an eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// an eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// one step this way
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction8::North => Vec2::new(0, -1),
+            Direction8::NorthEast => Vec2::new(1, -1),
+            Direction8::East => Vec2::new(1, 0),
+            Direction8::SouthEast => Vec2::new(1, 1),
+            Direction8::South => Vec2::new(0, 1),
+            Direction8::SouthWest => Vec2::new(-1, 1),
+            Direction8::West => Vec2::new(-1, 0),
+            Direction8::NorthWest => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::North => Direction8::North,
+            Direction4::East => Direction8::East,
+            Direction4::South => Direction8::South,
+            Direction4::West => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vec2::new(3, -2);
+        let b = Vec2::new(-1, 4);
+        assert_eq!(a + b, Vec2::new(2, 2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        assert_eq!(-a, Vec2::new(-3, 2));
+        assert_eq!(Vec2::new(i32::MAX, 0).checked_add(Vec2::new(1, 0)), None);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1, 1);
+        let b = Vec2::new(4, -3);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+        let corner = Vec2::new(i32::MIN, i32::MIN);
+        let opposite = Vec2::new(i32::MAX, i32::MAX);
+        assert_eq!(corner.manhattan(opposite), 2 * u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn conversion_to_grid_positions() {
+        assert_eq!(Pos::try_from(Vec2::new(2, 5)), Ok(Pos::new(2, 5)));
+        assert!(Pos::try_from(Vec2::new(-1, 5)).is_err());
+        assert_eq!(Vec2::try_from(Pos::new(2, 5)), Ok(Vec2::new(2, 5)));
+        assert!(Vec2::try_from(Pos::new(usize::MAX, 0)).is_err());
+    }
+
+    #[test]
+    fn turning() {
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+        }
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(direction.delta().chebyshev(Vec2::ZERO), 1);
+        }
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+        assert_eq!(Direction8::from(Direction4::West).delta(), Vec2::new(-1, 0));
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::{self, Display};
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction4, Direction8, ParseError, Vec2};
+
+/// where a cell is in a grid, counting from the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Pos { x, y }
+    }
+}
+
+/// a rectangle of cells, stored row after row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// a grid from its cells listed row after row
+    ///
+    /// panics unless there are exactly `width * height` of them
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// read a grid with one cell per character, turning each character into a cell with
+    /// `cell`. anything it turns down is reported as not being `expected`
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        Self::parse_with(input, |_, ch| cell(ch).ok_or_else(|| expected.to_string()))
+    }
+
+    /// like `parse`, but `cell` also gets told where it is, and says what it expected
+    /// instead when it turns a character down
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(Pos, char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, (offset, ch)) in line.char_indices().enumerate() {
+                let value = cell(Pos::new(x, y), ch).map_err(|expected| {
+                    ParseError::at(y, line, &line[offset..offset + ch.len_utf8()], expected)
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(ParseError::at(y, line, line, format!("a row {} wide", w)));
+                }
+                _ => (),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// the cell at `pos`, or `None` if that's off the grid
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// the position a step of `delta` away from `pos`, if that's still on the grid
+    pub fn offset(&self, pos: Pos, delta: Vec2) -> Option<Pos> {
+        let to = Vec2::try_from(pos).ok()?.checked_add(delta)?;
+        self.pos(to)
+    }
+
+    /// the grid position for a point, if the point is on the grid
+    pub fn pos(&self, point: Vec2) -> Option<Pos> {
+        Pos::try_from(point).ok().filter(|pos| self.contains(*pos))
+    }
+
+    /// every position on the grid, row after row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// every cell along with where it is, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// positions from `start` onwards, a step of `step` at a time, until the edge
+    pub fn ray(&self, start: Pos, step: Vec2) -> impl Iterator<Item = Pos> + '_ {
+        iter::successors(self.contains(start).then_some(start), move |pos| {
+            self.offset(*pos, step)
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // a zero width grid still has rows, they're just empty
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.line(Pos::new(x, 0), Direction4::South.delta()))
+    }
+
+    /// every diagonal running down and to the right, starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Pos::new(0, y))
+            .chain((1..self.width).map(|x| Pos::new(x, 0)));
+        starts
+            .filter(|_| self.width > 0 && self.height > 0)
+            .map(move |start| self.line(start, Direction8::SouthEast.delta()))
+    }
+
+    /// every diagonal running down and to the left, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| Pos::new(x, 0))
+            .chain((1..self.height).map(|y| Pos::new(self.width.wrapping_sub(1), y)));
+        starts
+            .filter(|_| self.width > 0 && self.height > 0)
+            .map(move |start| self.line(start, Direction8::SouthWest.delta()))
+    }
+
+    fn line(&self, start: Pos, step: Vec2) -> impl Iterator<Item = &T> {
+        self.ray(start, step).map(move |pos| &self[pos])
+    }
+
+    /// the up to four positions sharing an edge with `pos`
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction.delta()))
+    }
+
+    /// the up to eight positions sharing an edge or a corner with `pos`
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction.delta()))
+    }
+
+    /// the same shape of grid with every cell run through `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// the cell at a position, panicking if it's off the grid
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "({}, {}) is off a {}x{} grid",
+                pos.x, pos.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is off a {}x{} grid", pos.x, pos.y, width, height),
+        }
+    }
+}
+
+/// one line per row, with nothing between cells, so a grid of characters prints the way
+/// it was parsed
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some, "a letter").unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parses_and_prints_back() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 'f');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_cell() {
+        let error = Grid::parse("12\n3x\n", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        let error = Grid::parse("12\n3\n", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(error.expected, "a row 2 wide");
+    }
+
+    #[test]
+    fn lines_through_the_grid() {
+        let grid = letters();
+        let rows = grid
+            .rows()
+            .map(|r| r.iter().collect())
+            .collect::<Vec<String>>();
+        assert_eq!(rows, ["abc", "def"]);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = letters();
+        let corner = grid.neighbours4(Pos::new(0, 0)).collect::<Vec<Pos>>();
+        assert_eq!(corner, [Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Pos::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn empty_grids_have_no_lines() {
+        let grid = Grid::parse("", Some, "a letter").unwrap();
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.diagonals().count(), 0);
+        assert_eq!(grid.anti_diagonals().count(), 0);
+    }
+}
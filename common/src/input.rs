@@ -0,0 +1,52 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+
+/// puzzle input along with a name for where it came from
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub source: String,
+    pub text: String,
+}
+
+/// read the whole puzzle input from a file, or from stdin if the path is left out or `-`
+pub fn read_input(path: Option<&Path>) -> anyhow::Result<Input> {
+    let input = match path {
+        Some(path) if path != Path::new("-") => {
+            let source = path.display().to_string();
+            let bytes = fs::read(path).with_context(|| format!("failed to read {}", source))?;
+            let text = into_text(bytes, &source)?;
+            Input { source, text }
+        }
+        _ => {
+            let mut bytes = Vec::new();
+            io::stdin()
+                .read_to_end(&mut bytes)
+                .context("failed to read stdin")?;
+            let text = into_text(bytes, "stdin")?;
+            Input {
+                source: String::from("stdin"),
+                text,
+            }
+        }
+    };
+
+    // an empty input always gives a meaningless answer, so refuse it up front
+    if input.text.trim().is_empty() {
+        bail!("{} is empty", input.source);
+    }
+
+    Ok(input)
+}
+
+fn into_text(bytes: Vec<u8>, source: &str) -> anyhow::Result<String> {
+    String::from_utf8(bytes).map_err(|e| {
+        anyhow!(
+            "{} is not valid UTF-8 (bad byte at offset {})",
+            source,
+            e.utf8_error().valid_up_to()
+        )
+    })
+}
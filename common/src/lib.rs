@@ -0,0 +1,19 @@
+extern crate anyhow;
+
+mod error;
+pub use error::{parse_num, Location, ParseError};
+
+mod geometry;
+pub use geometry::{Direction4, Direction8, Vec2};
+
+mod grid;
+pub use grid::{Grid, Pos};
+
+mod input;
+pub use input::{read_input, Input};
+
+mod render;
+pub use render::{Cell, Colour, Picture, Style};
+
+mod solution;
+pub use solution::{Part, Solution};
@@ -0,0 +1,275 @@
+use std::fmt::{self, Display, Write};
+
+use crate::{Grid, Pos};
+
+/// the colours a picture can be drawn in: the ones every terminal's palette has, or any
+/// other for terminals that take 24-bit colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    /// colours that stand out from each other and from the background, for telling
+    /// groups of things apart
+    pub const PALETTE: [Colour; 6] = [
+        Colour::Cyan,
+        Colour::Magenta,
+        Colour::Yellow,
+        Colour::Green,
+        Colour::Blue,
+        Colour::Red,
+    ];
+
+    /// red, green and blue, with the named colours as a typical terminal shows them
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Colour::Black => (0, 0, 0),
+            Colour::Red => (205, 49, 49),
+            Colour::Green => (13, 188, 121),
+            Colour::Yellow => (229, 229, 16),
+            Colour::Blue => (36, 114, 200),
+            Colour::Magenta => (188, 63, 188),
+            Colour::Cyan => (17, 168, 205),
+            Colour::White => (229, 229, 229),
+            Colour::Grey => (118, 118, 118),
+            Colour::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// the colour `step` steps of `steps` along the way from `from` to `to`, for shading
+    /// things by how much of something there is
+    pub fn ramp(from: Colour, to: Colour, step: u32, steps: u32) -> Colour {
+        if steps == 0 {
+            return to;
+        }
+        let step = step.min(steps);
+        let mix = |a: u8, b: u8| {
+            let (a, b) = (u32::from(a), u32::from(b));
+            // weighted average of a and b, which always fits back in a u8
+            ((a * (steps - step) + b * step) / steps) as u8
+        };
+        let (from, to) = (from.rgb(), to.rgb());
+        Colour::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+
+    /// the ANSI code for drawing text, or the background behind it, in this colour
+    fn ansi(self, background: bool) -> String {
+        let code = match self {
+            Colour::Black => 30,
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+            Colour::Rgb(r, g, b) => {
+                let layer = if background { 48 } else { 38 };
+                return format!("{};2;{};{};{}", layer, r, g, b);
+            }
+        };
+        // background codes are the foreground ones shifted up by 10
+        (code + if background { 10 } else { 0 }).to_string()
+    }
+}
+
+/// how to draw a cell, with everything left out meaning the terminal's default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub fg: Option<Colour>,
+    pub bg: Option<Colour>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const PLAIN: Style = Style {
+        fg: None,
+        bg: None,
+        bold: false,
+    };
+
+    /// text in `colour`
+    pub const fn fg(colour: Colour) -> Self {
+        Style {
+            fg: Some(colour),
+            ..Style::PLAIN
+        }
+    }
+
+    /// the same, on a background of `colour`
+    pub const fn on(self, colour: Colour) -> Self {
+        Style {
+            bg: Some(colour),
+            ..self
+        }
+    }
+
+    pub const fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    /// the escape sequence switching to this style from plain text
+    fn escape(self) -> String {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(String::from("1"));
+        }
+        codes.extend(self.fg.map(|colour| colour.ansi(false)));
+        codes.extend(self.bg.map(|colour| colour.ansi(true)));
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// one character of a picture and how to draw it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub const fn new(ch: char, style: Style) -> Self {
+        Cell { ch, style }
+    }
+
+    pub const fn plain(ch: char) -> Self {
+        Cell::new(ch, Style::PLAIN)
+    }
+}
+
+/// a grid of styled characters showing some puzzle state, with a key saying what the
+/// styles mean
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pub cells: Grid<Cell>,
+    pub key: Vec<(Cell, String)>,
+}
+
+impl Picture {
+    /// draw every cell of `grid` with `cell`, which also gets told where it is
+    pub fn draw<T>(grid: &Grid<T>, mut cell: impl FnMut(Pos, &T) -> Cell) -> Self {
+        let cells = grid.iter().map(|(pos, value)| cell(pos, value)).collect();
+        Picture {
+            cells: Grid::new(grid.width(), grid.height(), cells),
+            key: Vec::new(),
+        }
+    }
+
+    /// explain what a kind of cell means under the picture
+    pub fn with_key(mut self, cell: Cell, meaning: impl Into<String>) -> Self {
+        self.key.push((cell, meaning.into()));
+        self
+    }
+
+    /// the picture with ANSI escapes for its colours, for printing to a terminal
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        let lines = self.cells.rows().map(|row| row.to_vec()).chain(
+            self.key
+                .iter()
+                .map(|(cell, meaning)| key_line(*cell, meaning)),
+        );
+        for line in lines {
+            // only switch styles where they change, and go back to plain at the end of
+            // every line so nothing leaks past the picture
+            let mut current = Style::PLAIN;
+            for cell in line {
+                if cell.style != current {
+                    if current != Style::PLAIN {
+                        out.push_str("\x1b[0m");
+                    }
+                    if cell.style != Style::PLAIN {
+                        out.push_str(&cell.style.escape());
+                    }
+                    current = cell.style;
+                }
+                out.push(cell.ch);
+            }
+            if current != Style::PLAIN {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// a line of the key: the cell itself, then what it means in plain text
+fn key_line(cell: Cell, meaning: &str) -> Vec<Cell> {
+    let mut line = vec![cell, Cell::plain(' ')];
+    line.extend(meaning.chars().map(Cell::plain));
+    line
+}
+
+/// the picture without colours, for places escapes would end up as junk
+impl Display for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            for cell in row {
+                f.write_char(cell.ch)?;
+            }
+            writeln!(f)?;
+        }
+        for (cell, meaning) in &self.key {
+            writeln!(f, "{} {}", cell.ch, meaning)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        let grid = Grid::parse("ab\nba\n", Some, "a letter").unwrap();
+        Picture::draw(&grid, |_, ch| match ch {
+            'a' => Cell::new('a', Style::fg(Colour::Red).bold()),
+            _ => Cell::plain(*ch),
+        })
+        .with_key(Cell::new('a', Style::fg(Colour::Red).bold()), "an a")
+    }
+
+    #[test]
+    fn plain_text_drops_the_styles() {
+        assert_eq!(picture().to_string(), "ab\nba\na an a\n");
+    }
+
+    #[test]
+    fn escapes_only_where_the_style_changes() {
+        assert_eq!(
+            picture().to_ansi(),
+            "\x1b[1;31ma\x1b[0mb\nb\x1b[1;31ma\x1b[0m\n\x1b[1;31ma\x1b[0m an a\n"
+        );
+        let grid = Grid::new(3, 1, vec![0, 0, 0]);
+        let green = Picture::draw(&grid, |_, _| {
+            Cell::new('.', Style::fg(Colour::Green).on(Colour::Black))
+        });
+        assert_eq!(green.to_ansi(), "\x1b[32;40m...\x1b[0m\n");
+        let shaded = Picture::draw(&grid, |_, _| {
+            Cell::new('.', Style::PLAIN.on(Colour::Rgb(1, 2, 3)))
+        });
+        assert_eq!(shaded.to_ansi(), "\x1b[48;2;1;2;3m...\x1b[0m\n");
+    }
+
+    #[test]
+    fn ramps_run_between_the_two_colours() {
+        let (from, to) = (Colour::Rgb(0, 100, 200), Colour::Rgb(100, 100, 0));
+        assert_eq!(Colour::ramp(from, to, 0, 4), from);
+        assert_eq!(Colour::ramp(from, to, 1, 4), Colour::Rgb(25, 100, 150));
+        assert_eq!(Colour::ramp(from, to, 4, 4), to);
+        assert_eq!(Colour::ramp(from, to, 9, 4), to);
+        assert_eq!(Colour::ramp(from, to, 0, 0), to);
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::{self, Display};
+
+use crate::{ParseError, Picture};
+
+/// which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u32> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(n: u32) -> anyhow::Result<Self> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => anyhow::bail!("part must be 1 or 2, not {}", n),
+        }
+    }
+}
+
+/// a day's puzzle: one parser shared by both parts
+pub trait Solution {
+    /// day of the advent calendar this solves
+    const DAY: u32;
+
+    /// what `parse` turns the raw puzzle input into
+    type Input;
+    /// the answer type both parts produce
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Output>;
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Output>;
+
+    /// run whichever part is asked for on already parsed input
+    fn solve(part: Part, input: &Self::Input) -> anyhow::Result<Self::Output> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// parse the raw puzzle input and solve one part of it, for calling a day from other
+    /// code without going through files or the terminal
+    fn answer(part: Part, input: &str) -> anyhow::Result<Self::Output> {
+        let data = Self::parse(input)?;
+        Self::solve(part, &data)
+    }
+
+    /// solve one part and draw the puzzle state behind its answer, for days on a grid,
+    /// working both out together so drawing doesn't mean solving twice. days with
+    /// nothing to show leave this as is
+    fn render(
+        _part: Part,
+        _input: &Self::Input,
+    ) -> anyhow::Result<Option<(Self::Output, Picture)>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,22 @@
+//! fuzz targets for every day, one per `dayNN` binary
+//!
+//! run one with `cargo +nightly fuzz run day07 -- -timeout=10`. fuzz builds keep debug
+//! assertions on, so arithmetic overflow panics like any other bug, and a solution stuck
+//! in a loop shows up as a timeout
+
+use aoc_common::{Part, Solution};
+
+/// feed arbitrary bytes to a day's parser, then anything it accepts to both parts
+///
+/// errors are fine, since most inputs are nonsense, but nothing may panic
+pub fn check<S: Solution>(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(input) = S::parse(text) else {
+        return;
+    };
+    for part in Part::BOTH {
+        let _ = S::solve(part, &input);
+    }
+}